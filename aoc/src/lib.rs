@@ -0,0 +1,359 @@
+//! Plumbing shared by the days: the [`Solution`] trait every day implements, finding and reading
+//! puzzle inputs, locating parse errors within them, [parsers](parse) for the common formats, and
+//! [records](Record) of the answers for scripts to read.
+//!
+//! Inputs live outside the code, by convention in `inputs/dayNN.txt` at the workspace root, so a
+//! solution can be run against any input without recompiling.
+
+pub mod parse;
+
+use anyhow::{bail, Context, Result};
+use serde::{Serialize, Serializer};
+use std::cell::RefCell;
+use std::fmt::Display;
+use std::fs;
+use std::io::Read;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use std::time::{Duration, Instant};
+use thiserror::Error;
+
+/// A day's puzzle: how to read its input, and how to answer each part from it.
+pub trait Solution {
+    /// The day of December the puzzle is for.
+    const DAY: u32;
+
+    type Input;
+    type Part1: Display;
+    type Part2: Display;
+
+    fn parse(input: &str) -> Result<Self::Input>;
+    fn part1(input: &Self::Input) -> Self::Part1;
+    fn part2(input: &Self::Input) -> Self::Part2;
+}
+
+/// Parses `input` and answers `part`, or both parts for `None`, as `(part, answer)` pairs.
+pub fn answers<S: Solution>(input: &str, part: Option<u32>) -> Result<Vec<(u32, String)>> {
+    Ok(records::<S>(input, part)?
+        .into_iter()
+        .map(|r| (r.part, r.answer))
+        .collect())
+}
+
+/// One part's answer, with how long it took and what the day [noted](note) on the way to it.
+///
+/// It serializes with the elapsed time in nanoseconds and the diagnostics as an object:
+///
+/// `{"day":3,"part":1,"answer":"198","elapsed_ns":1250,"diagnostics":{"gamma":"10110"}}`
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct Record {
+    pub day: u32,
+    pub part: u32,
+    pub answer: String,
+    /// The time the part took, not counting parsing the input.
+    #[serde(rename = "elapsed_ns", serialize_with = "nanos")]
+    pub elapsed: Duration,
+    /// Values the day noted as `(name, value)`, in the order it first noted them.
+    #[serde(serialize_with = "in_order")]
+    pub diagnostics: Vec<(String, String)>,
+}
+
+impl Record {
+    /// The record as a line of JSON.
+    pub fn to_json(&self) -> String {
+        serde_json::to_string(self).expect("records have string keys")
+    }
+}
+
+/// Serializes a duration as whole nanoseconds.
+fn nanos<S: Serializer>(elapsed: &Duration, serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.serialize_u128(elapsed.as_nanos())
+}
+
+/// Serializes `(name, value)` pairs as an object, keeping their order.
+fn in_order<S: Serializer>(pairs: &[(String, String)], serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.collect_map(pairs.iter().map(|(k, v)| (k, v)))
+}
+
+thread_local! {
+    /// What's been noted since the current part started.
+    static NOTES: RefCell<Vec<(String, String)>> = const { RefCell::new(Vec::new()) };
+}
+
+/// Notes a value that went into an answer, such as an intermediate result, to go in the answer's
+/// [`Record`] as a diagnostic. Noting a name again replaces its value.
+pub fn note(name: &str, value: impl Display) {
+    NOTES.with_borrow_mut(|notes| {
+        let value = value.to_string();
+        match notes.iter_mut().find(|(n, _)| n == name) {
+            Some((_, v)) => *v = value,
+            None => notes.push((name.to_string(), value)),
+        }
+    })
+}
+
+/// Runs `f`, timing it and collecting what it notes.
+fn recorded<T>(f: impl FnOnce() -> T) -> (T, Duration, Vec<(String, String)>) {
+    NOTES.with_borrow_mut(Vec::clear);
+    let start = Instant::now();
+    let out = f();
+    let elapsed = start.elapsed();
+    (out, elapsed, NOTES.with_borrow_mut(std::mem::take))
+}
+
+/// Parses `input` and answers `part`, or both parts for `None`, recording each answer.
+pub fn records<S: Solution>(input: &str, part: Option<u32>) -> Result<Vec<Record>> {
+    let input = S::parse(input)?;
+    let mut ret = Vec::with_capacity(2);
+    for p in [1, 2] {
+        if part.is_some_and(|part| part != p) {
+            continue;
+        }
+        let (answer, elapsed, diagnostics) = recorded(|| match p {
+            1 => S::part1(&input).to_string(),
+            _ => S::part2(&input).to_string(),
+        });
+        ret.push(Record {
+            day: S::DAY,
+            part: p,
+            answer,
+            elapsed,
+            diagnostics,
+        });
+    }
+    Ok(ret)
+}
+
+/// Prints an answer. Some answers are drawn rather than written, so those get lines of their own.
+pub fn print_answer(part: u32, answer: &str) {
+    if answer.contains('\n') {
+        println!("Part {}:\n{}", part, answer);
+    } else {
+        println!("Part {}: {}", part, answer);
+    }
+}
+
+/// The whole of a day's binary: answers both parts for the input named by its argument, or the
+/// default input. With `--json`, each answer is printed as a JSON [`Record`] on a line of its own.
+pub fn run<S: Solution>() -> Result<()> {
+    let args = Args::parse(std::env::args().skip(1))?;
+    let input = read_input(S::DAY, args.input.as_deref())?;
+    for record in records::<S>(&input, None)? {
+        if args.json {
+            println!("{}", record.to_json());
+        } else {
+            print_answer(record.part, &record.answer);
+        }
+    }
+    Ok(())
+}
+
+/// A day binary's arguments.
+#[derive(Debug, Default, PartialEq)]
+struct Args {
+    /// Where to read the input from, as for [`read_input`].
+    input: Option<String>,
+    json: bool,
+}
+
+impl Args {
+    fn parse(args: impl Iterator<Item = String>) -> Result<Args> {
+        let mut parsed = Args::default();
+        for arg in args {
+            if arg == "--json" {
+                parsed.json = true;
+            } else if parsed.input.is_none() && (arg == "-" || !arg.starts_with('-')) {
+                parsed.input = Some(arg);
+            } else {
+                bail!(
+                    "unexpected argument {:?}; usage: [<input path>|-] [--json]",
+                    arg
+                );
+            }
+        }
+        Ok(parsed)
+    }
+}
+
+/// The conventional input file for `day`: `inputs/dayNN.txt` under the current directory if it
+/// exists there, otherwise under the workspace root.
+pub fn default_input_path(day: u32) -> PathBuf {
+    let relative = PathBuf::from(format!("inputs/day{:02}.txt", day));
+    if relative.exists() {
+        return relative;
+    }
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .parent()
+        .expect("aoc crate lives in the workspace")
+        .join(relative)
+}
+
+/// Reads the input for `day` from `source`: `-` for stdin, a path, or `None` for the default
+/// input file.
+pub fn read_input(day: u32, source: Option<&str>) -> Result<String> {
+    match source {
+        Some("-") => {
+            let mut input = String::new();
+            std::io::stdin()
+                .read_to_string(&mut input)
+                .context("reading input from stdin")?;
+            Ok(input)
+        }
+        Some(path) => read_file(Path::new(path)),
+        None => read_file(&default_input_path(day)),
+    }
+}
+
+fn read_file(path: &Path) -> Result<String> {
+    fs::read_to_string(path).with_context(|| format!("reading input from {}", path.display()))
+}
+
+/// An error parsing one line of an input, with the line it's on, counting from 1.
+#[derive(Clone, Debug, Eq, PartialEq, Error)]
+#[error("line {line}, {error}")]
+pub struct LineError<E> {
+    pub line: usize,
+    pub error: E,
+}
+
+/// Parses every line of `input` as a `T`.
+pub fn parse_lines<T: FromStr>(input: &str) -> Result<Vec<T>, LineError<T::Err>> {
+    input
+        .lines()
+        .enumerate()
+        .map(|(i, l)| l.parse().map_err(|error| LineError { line: i + 1, error }))
+        .collect()
+}
+
+/// The whitespace-separated words of `line`, each with the column it starts at, counting from 1.
+pub fn words(line: &str) -> impl Iterator<Item = (usize, &str)> + '_ {
+    let mut rest = line;
+    let mut column = 1;
+    std::iter::from_fn(move || {
+        let trimmed = rest.trim_start();
+        column += rest[..rest.len() - trimmed.len()].chars().count();
+        let end = trimmed.find(char::is_whitespace).unwrap_or(trimmed.len());
+        let (word, tail) = trimmed.split_at(end);
+        rest = tail;
+        let start = column;
+        column += word.chars().count();
+        (!word.is_empty()).then_some((start, word))
+    })
+}
+
+/// The column just past the end of `line`, where an error about something missing points.
+pub fn end_column(line: &str) -> usize {
+    line.chars().count() + 1
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        default_input_path, note, parse_lines, read_input, records, words, Args, Record, Solution,
+    };
+    use std::time::Duration;
+
+    /// Adds up the numbers on each line, noting how many there were along the way.
+    struct Sum;
+
+    impl Solution for Sum {
+        const DAY: u32 = 1;
+        type Input = Vec<u32>;
+        type Part1 = u32;
+        type Part2 = usize;
+
+        fn parse(input: &str) -> anyhow::Result<Vec<u32>> {
+            Ok(parse_lines(input)?)
+        }
+
+        fn part1(input: &Vec<u32>) -> u32 {
+            note("count", 0);
+            note("count", input.len());
+            input.iter().sum()
+        }
+
+        fn part2(input: &Vec<u32>) -> usize {
+            input.len()
+        }
+    }
+
+    #[test]
+    fn default_inputs_are_found() {
+        let path = default_input_path(1);
+        assert!(path.ends_with("inputs/day01.txt"));
+        assert!(path.exists());
+        assert!(read_input(1, None).unwrap().starts_with("143\n"));
+    }
+
+    #[test]
+    fn missing_input_names_the_file() {
+        let err = read_input(1, Some("no/such/input.txt")).unwrap_err();
+        assert!(format!("{:#}", err).contains("no/such/input.txt"));
+    }
+
+    #[test]
+    fn words_and_columns() {
+        let found: Vec<_> = words("  fold along\ty=7 ").collect();
+        assert_eq!(vec![(3, "fold"), (8, "along"), (14, "y=7")], found);
+        assert_eq!(None, words("   ").next());
+        assert_eq!(vec![(3, "é")], words("é é").skip(1).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn line_errors_count_from_one() {
+        assert_eq!(Ok(vec![1, 2]), parse_lines::<u8>("1\n2\n"));
+        let err = parse_lines::<u8>("1\n2\nx\n").unwrap_err();
+        assert_eq!(3, err.line);
+        assert!(err.to_string().starts_with("line 3, "));
+    }
+
+    #[test]
+    fn records_collect_notes() {
+        let both = records::<Sum>("1\n2\n", None).unwrap();
+        assert_eq!(2, both.len());
+        assert_eq!((1, "3"), (both[0].part, both[0].answer.as_str()));
+        assert_eq!(
+            vec![("count".to_string(), "2".to_string())],
+            both[0].diagnostics
+        );
+        // Notes don't carry over from one part to the next
+        assert!(both[1].diagnostics.is_empty());
+
+        let part2 = records::<Sum>("1\n", Some(2)).unwrap();
+        assert_eq!(vec![2], part2.iter().map(|r| r.part).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn records_as_json() {
+        let mut record = Record {
+            day: 3,
+            part: 2,
+            answer: "198".to_string(),
+            elapsed: Duration::from_micros(12),
+            diagnostics: vec![],
+        };
+        assert_eq!(
+            r#"{"day":3,"part":2,"answer":"198","elapsed_ns":12000,"diagnostics":{}}"#,
+            record.to_json()
+        );
+        record.diagnostics = vec![
+            ("gamma".to_string(), "10110".to_string()),
+            ("epsilon".to_string(), "01001".to_string()),
+        ];
+        assert!(record
+            .to_json()
+            .ends_with(r#""diagnostics":{"gamma":"10110","epsilon":"01001"}}"#));
+    }
+
+    #[test]
+    fn day_binary_args() {
+        let args = |s: &str| Args::parse(s.split_whitespace().map(String::from));
+        assert_eq!(Args::default(), args("").unwrap());
+        let parsed = args("--json my-input.txt").unwrap();
+        assert_eq!(Some("my-input.txt"), parsed.input.as_deref());
+        assert!(parsed.json);
+        assert_eq!(Some("-"), args("-").unwrap().input.as_deref());
+        assert!(args("--jsn").is_err());
+        assert!(args("a.txt b.txt").is_err());
+    }
+}
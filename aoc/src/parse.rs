@@ -0,0 +1,463 @@
+//! Parsers for the formats puzzle inputs keep coming back to: blank-line separated sections,
+//! comma-separated integers, digit grids, `a -> b` rules and `x=a..b` ranges.
+//!
+//! They're built from a few combinators, which a day can also use directly for its own formats.
+//! A [`Parser`] takes some text and returns what it parsed along with the rest of the text, or a
+//! [`Failure`] saying what it expected where. [`complete`] runs a parser over a whole line and
+//! turns a failure into a [`ParseError`] with the column it happened at.
+
+use crate::LineError;
+use std::ops::RangeInclusive;
+use std::str::FromStr;
+use thiserror::Error;
+
+/// Why some text doesn't parse: what was expected at which column, and what was there instead.
+/// Columns count from 1.
+#[derive(Clone, Debug, Eq, PartialEq, Error)]
+#[error("column {column}: expected {expected}, found {found}")]
+pub struct ParseError {
+    pub column: usize,
+    pub expected: String,
+    pub found: String,
+}
+
+/// Where a parser failed, as the input it had left, and what it expected there.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Failure {
+    remaining: usize,
+    expected: String,
+}
+
+impl Failure {
+    /// A failure at the start of `rest`, which must be the end of the text being parsed.
+    pub fn new(rest: &str, expected: impl Into<String>) -> Failure {
+        Failure {
+            remaining: rest.len(),
+            expected: expected.into(),
+        }
+    }
+
+    /// Locates the failure within `input`, the whole text it was parsing.
+    fn locate(self, input: &str) -> ParseError {
+        let at = input.len() - self.remaining;
+        let rest = &input[at..];
+        let found = match rest.split_whitespace().next() {
+            Some(word) if rest.starts_with(word) => format!("{:?}", word),
+            _ if rest.is_empty() => "the end of the line".to_string(),
+            _ => format!("{:?}", rest.chars().next().unwrap()),
+        };
+        ParseError {
+            column: input[..at].chars().count() + 1,
+            expected: self.expected,
+            found,
+        }
+    }
+}
+
+/// A parsed value and the text left after it.
+pub type PResult<'a, T> = Result<(T, &'a str), Failure>;
+
+/// Parses a `T` from the start of some text.
+pub trait Parser<'a, T> {
+    fn parse(&self, input: &'a str) -> PResult<'a, T>;
+}
+
+impl<'a, T, F: Fn(&'a str) -> PResult<'a, T>> Parser<'a, T> for F {
+    fn parse(&self, input: &'a str) -> PResult<'a, T> {
+        self(input)
+    }
+}
+
+/// Runs `parser` over the whole of `input`, failing if it leaves anything unparsed.
+pub fn complete<'a, T>(parser: impl Parser<'a, T>, input: &'a str) -> Result<T, ParseError> {
+    match parser.parse(input) {
+        Ok((value, "")) => Ok(value),
+        Ok((_, rest)) => Err(Failure::new(rest, "the end of the line").locate(input)),
+        Err(failure) => Err(failure.locate(input)),
+    }
+}
+
+/// Exactly `literal`.
+pub fn tag<'a>(literal: &'static str) -> impl Parser<'a, &'a str> {
+    move |input: &'a str| match input.strip_prefix(literal) {
+        Some(rest) => Ok((&input[..literal.len()], rest)),
+        None => Err(Failure::new(input, format!("{:?}", literal))),
+    }
+}
+
+/// Any whitespace, including none.
+pub fn space0<'a>() -> impl Parser<'a, &'a str> {
+    move |input: &'a str| {
+        let rest = input.trim_start();
+        Ok((&input[..input.len() - rest.len()], rest))
+    }
+}
+
+/// Some whitespace, at least one character of it.
+pub fn space1<'a>() -> impl Parser<'a, &'a str> {
+    move |input: &'a str| match space0().parse(input)? {
+        ("", _) => Err(Failure::new(input, "whitespace")),
+        ok => Ok(ok),
+    }
+}
+
+/// Everything up to the first `delimiter`, which is left unparsed. Fails if there isn't one.
+pub fn until<'a>(delimiter: &'static str) -> impl Parser<'a, &'a str> {
+    move |input: &'a str| match input.find(delimiter) {
+        Some(i) => Ok((&input[..i], &input[i..])),
+        None => Err(Failure::new(
+            &input[input.len()..],
+            format!("{:?}", delimiter),
+        )),
+    }
+}
+
+/// Everything that's left.
+pub fn rest<'a>() -> impl Parser<'a, &'a str> {
+    move |input: &'a str| Ok((input, &input[input.len()..]))
+}
+
+/// An integer, with an optional sign.
+pub fn int<'a, T: FromStr>() -> impl Parser<'a, T> {
+    move |input: &'a str| {
+        let unsigned = input.strip_prefix(['-', '+']).unwrap_or(input);
+        let digits = unsigned.len()
+            - unsigned
+                .trim_start_matches(|c: char| c.is_ascii_digit())
+                .len();
+        let end = input.len() - unsigned.len() + digits;
+        match input[..end].parse() {
+            Ok(n) if digits > 0 => Ok((n, &input[end..])),
+            _ => Err(Failure::new(input, "an integer")),
+        }
+    }
+}
+
+/// A single decimal digit.
+pub fn digit<'a>() -> impl Parser<'a, u8> {
+    move |input: &'a str| match input.chars().next() {
+        Some(c @ '0'..='9') => Ok((c as u8 - b'0', &input[1..])),
+        _ => Err(Failure::new(input, "a digit")),
+    }
+}
+
+/// `parser`'s value, transformed by `f`.
+pub fn map<'a, T, U>(parser: impl Parser<'a, T>, f: impl Fn(T) -> U) -> impl Parser<'a, U> {
+    move |input: &'a str| parser.parse(input).map(|(value, rest)| (f(value), rest))
+}
+
+/// The text `parser` matched, parsed as a `T`, described as `what` if it isn't one.
+pub fn value<'a, T: FromStr>(
+    parser: impl Parser<'a, &'a str>,
+    what: &'static str,
+) -> impl Parser<'a, T> {
+    move |input: &'a str| {
+        let (text, rest) = parser.parse(input)?;
+        match text.parse() {
+            Ok(value) => Ok((value, rest)),
+            Err(_) => Err(Failure::new(input, what)),
+        }
+    }
+}
+
+/// `first` then `second`.
+pub fn pair<'a, A, B>(
+    first: impl Parser<'a, A>,
+    second: impl Parser<'a, B>,
+) -> impl Parser<'a, (A, B)> {
+    move |input: &'a str| {
+        let (a, rest) = first.parse(input)?;
+        let (b, rest) = second.parse(rest)?;
+        Ok(((a, b), rest))
+    }
+}
+
+/// `prefix` then `parser`, keeping only `parser`'s value.
+pub fn preceded<'a, P, T>(
+    prefix: impl Parser<'a, P>,
+    parser: impl Parser<'a, T>,
+) -> impl Parser<'a, T> {
+    map(pair(prefix, parser), |(_, value)| value)
+}
+
+/// `parser` then `suffix`, keeping only `parser`'s value.
+pub fn terminated<'a, T, S>(
+    parser: impl Parser<'a, T>,
+    suffix: impl Parser<'a, S>,
+) -> impl Parser<'a, T> {
+    map(pair(parser, suffix), |(value, _)| value)
+}
+
+/// `first` if it parses, otherwise `second`.
+pub fn either<'a, T>(first: impl Parser<'a, T>, second: impl Parser<'a, T>) -> impl Parser<'a, T> {
+    move |input: &'a str| {
+        first.parse(input).or_else(|a| {
+            second.parse(input).map_err(|b| {
+                // Report whichever got further, or both if neither did
+                match a.remaining.cmp(&b.remaining) {
+                    std::cmp::Ordering::Less => a,
+                    std::cmp::Ordering::Greater => b,
+                    std::cmp::Ordering::Equal => Failure {
+                        expected: format!("{} or {}", a.expected, b.expected),
+                        ..a
+                    },
+                }
+            })
+        })
+    }
+}
+
+/// One or more of `parser`, one after another.
+pub fn many1<'a, T>(parser: impl Parser<'a, T>) -> impl Parser<'a, Vec<T>> {
+    move |input: &'a str| {
+        let (first, mut rest) = parser.parse(input)?;
+        let mut values = vec![first];
+        while let Ok((value, after)) = parser.parse(rest) {
+            values.push(value);
+            rest = after;
+        }
+        Ok((values, rest))
+    }
+}
+
+/// One or more of `parser`, with `separator` between them.
+pub fn separated1<'a, T, S>(
+    parser: impl Parser<'a, T>,
+    separator: impl Parser<'a, S>,
+) -> impl Parser<'a, Vec<T>> {
+    move |input: &'a str| {
+        let (first, mut rest) = parser.parse(input)?;
+        let mut values = vec![first];
+        while let Ok((_, after)) = separator.parse(rest) {
+            let (value, after) = parser.parse(after)?;
+            values.push(value);
+            rest = after;
+        }
+        Ok((values, rest))
+    }
+}
+
+/// A range written `name=a..b`, inclusive at both ends.
+pub fn range<'a, T: FromStr>(name: &'static str) -> impl Parser<'a, RangeInclusive<T>> {
+    map(
+        pair(
+            preceded(pair(tag(name), tag("=")), int()),
+            preceded(tag(".."), int()),
+        ),
+        |(start, end)| start..=end,
+    )
+}
+
+/// Ranges for x, y and z, written `x=a..b,y=c..d,z=e..f`. Spaces may follow the commas.
+pub fn xyz_ranges<'a, T: FromStr>() -> impl Parser<'a, [RangeInclusive<T>; 3]> {
+    let comma = || pair(tag(","), space0());
+    map(
+        pair(
+            range("x"),
+            pair(preceded(comma(), range("y")), preceded(comma(), range("z"))),
+        ),
+        |(x, (y, z))| [x, y, z],
+    )
+}
+
+/// The blank-line separated sections of `input`, each with the line it starts on, counting from 1.
+/// Runs of blank lines count as one separator, and a section's text doesn't end in a newline.
+pub fn sections(input: &str) -> impl Iterator<Item = (usize, &str)> + '_ {
+    let mut lines = input.split_inclusive('\n').enumerate().peekable();
+    let mut offset = 0;
+    std::iter::from_fn(move || {
+        // Skip to the start of the next section
+        while let Some((_, l)) = lines.next_if(|(_, l)| l.trim().is_empty()) {
+            offset += l.len();
+        }
+        let &(first, _) = lines.peek()?;
+        let start = offset;
+        while let Some((_, l)) = lines.next_if(|(_, l)| !l.trim().is_empty()) {
+            offset += l.len();
+        }
+        Some((
+            first + 1,
+            input[start..offset].trim_end_matches(['\n', '\r']),
+        ))
+    })
+}
+
+/// A comma-separated list of integers, like `3,4,3,1,2`. Spaces may surround the commas.
+pub fn int_list<T: FromStr>(line: &str) -> Result<Vec<T>, ParseError> {
+    complete(
+        separated1(int(), pair(space0(), pair(tag(","), space0()))),
+        line,
+    )
+}
+
+/// Lines of decimal digits, all the same length, as rows of their values.
+pub fn digit_grid(input: &str) -> Result<Vec<Vec<u8>>, LineError<ParseError>> {
+    let mut rows: Vec<Vec<u8>> = vec![];
+    for (i, line) in input.lines().enumerate() {
+        let row = complete(many1(digit()), line).map_err(|error| LineError {
+            line: i + 1,
+            // Whatever stopped the digits wasn't one
+            error: ParseError {
+                expected: "a digit".to_string(),
+                ..error
+            },
+        })?;
+        if let Some(width) = rows.first().map(Vec::len) {
+            if row.len() != width {
+                let at = line
+                    .char_indices()
+                    .nth(width)
+                    .map_or(line.len(), |(i, _)| i);
+                let error = Failure::new(&line[at..], format!("{} digits", width)).locate(line);
+                return Err(LineError { line: i + 1, error });
+            }
+        }
+        rows.push(row);
+    }
+    Ok(rows)
+}
+
+/// A rule written `key -> value`, with each side parsed by its own `FromStr`.
+pub fn rule<K: FromStr, V: FromStr>(line: &str) -> Result<(K, V), ParseError> {
+    complete(
+        pair(
+            value(until(" -> "), "a rule's key"),
+            preceded(tag(" -> "), value(rest(), "a rule's value")),
+        ),
+        line,
+    )
+}
+
+/// Ranges for x, y and z, as [`xyz_ranges`] reads them, making up the whole of `line`.
+pub fn range_triple<T: FromStr>(line: &str) -> Result<[RangeInclusive<T>; 3], ParseError> {
+    complete(xyz_ranges(), line)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::parse::*;
+
+    fn error(column: usize, expected: &str, found: &str) -> ParseError {
+        ParseError {
+            column,
+            expected: expected.to_string(),
+            found: found.to_string(),
+        }
+    }
+
+    #[test]
+    fn combinators() {
+        assert_eq!(Ok(-12), complete(int::<i32>(), "-12"));
+        assert_eq!(
+            Err(error(1, "an integer", "\"-\"")),
+            complete(int::<i32>(), "-")
+        );
+        assert_eq!(
+            Err(error(1, "an integer", "\"300\"")),
+            complete(int::<u8>(), "300")
+        );
+        assert_eq!(
+            Err(error(3, "the end of the line", "\"x\"")),
+            complete(int::<u8>(), "12x")
+        );
+
+        let row = || preceded(space0(), separated1(int::<u32>(), space1()));
+        assert_eq!(Ok(vec![8, 2]), complete(row(), " 8  2"));
+        assert_eq!(
+            Err(error(4, "an integer", "\"x\"")),
+            complete(row(), " 8 x")
+        );
+        assert_eq!(
+            Err(error(3, "the end of the line", "\"-2\"")),
+            complete(row(), " 8-2")
+        );
+
+        let on_off = || either(map(tag("on"), |_| true), map(tag("off"), |_| false));
+        assert_eq!(Ok(false), complete(on_off(), "off"));
+        assert_eq!(
+            Err(error(1, "\"on\" or \"off\"", "\"of\"")),
+            complete(on_off(), "of")
+        );
+        assert_eq!(
+            Ok(vec![true, false]),
+            complete(separated1(on_off(), tag(" ")), "on off")
+        );
+        assert_eq!(
+            Err(error(4, "\"on\" or \"off\"", "the end of the line")),
+            complete(separated1(on_off(), tag(" ")), "on ")
+        );
+    }
+
+    #[test]
+    fn sections_and_their_lines() {
+        let input = "7,4,9\n\n22 13\n 8  2\n\n\n3 15\n";
+        let found: Vec<_> = sections(input).collect();
+        assert_eq!(vec![(1, "7,4,9"), (3, "22 13\n 8  2"), (7, "3 15")], found);
+        assert_eq!(None, sections("\n\n").next());
+    }
+
+    #[test]
+    fn int_lists() {
+        assert_eq!(Ok(vec![3, 4, 3, 1, 2]), int_list::<u32>("3,4,3,1,2"));
+        assert_eq!(Ok(vec![-1, 2]), int_list::<i32>("-1 , 2"));
+        assert_eq!(
+            Err(error(5, "an integer", "\"x\"")),
+            int_list::<u32>("1,2,x")
+        );
+        assert_eq!(
+            Err(error(5, "an integer", "the end of the line")),
+            int_list::<u32>("1,2,")
+        );
+    }
+
+    #[test]
+    fn digit_grids() {
+        assert_eq!(Ok(vec![vec![1, 2], vec![3, 4]]), digit_grid("12\n34\n"));
+        let err = digit_grid("12\n3a\n").unwrap_err();
+        assert_eq!(2, err.line);
+        assert_eq!(error(2, "a digit", "\"a\""), err.error);
+        let err = digit_grid("12\n345\n").unwrap_err();
+        assert_eq!(error(3, "2 digits", "\"5\""), err.error);
+        let err = digit_grid("12\n3\n").unwrap_err();
+        assert_eq!(error(2, "2 digits", "the end of the line"), err.error);
+    }
+
+    #[test]
+    fn rules() {
+        assert_eq!(Ok(("CH".to_string(), 'B')), rule::<String, char>("CH -> B"));
+        assert_eq!(
+            Err(error(7, "a rule's value", "\"BB\"")),
+            rule::<String, char>("CH -> BB")
+        );
+        assert_eq!(
+            Err(error(5, "\" -> \"", "the end of the line")),
+            rule::<String, char>("CH B")
+        );
+    }
+
+    #[test]
+    fn ranges() {
+        assert_eq!(
+            Ok([10..=12, -3..=4, 0..=0]),
+            range_triple::<i32>("x=10..12,y=-3..4,z=0..0")
+        );
+        assert_eq!(
+            Ok((241..=273, -97..=-63)),
+            complete(
+                preceded(
+                    tag("target area: "),
+                    pair(range::<i32>("x"), preceded(tag(", "), range("y")))
+                ),
+                "target area: x=241..273, y=-97..-63"
+            )
+        );
+        assert_eq!(
+            Err(error(10, "\"y\"", "\"w=3..4,z=0..0\"")),
+            range_triple::<i32>("x=10..12,w=3..4,z=0..0")
+        );
+        assert_eq!(
+            Err(error(5, "\"..\"", "\".12,y=3..4,z=0..0\"")),
+            range_triple::<i32>("x=10.12,y=3..4,z=0..0")
+        );
+    }
+}
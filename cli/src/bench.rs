@@ -0,0 +1,456 @@
+//! `aoc-bench`: times each day's parse and parts, measures how much memory each needs at its
+//! peak, and compares both against a baseline saved by an earlier run.
+
+use anyhow::{anyhow, bail, Context, Result};
+use aoc::Solution;
+use std::alloc::{GlobalAlloc, Layout};
+use std::collections::HashMap;
+use std::fmt::{self, Display, Formatter, Write as _};
+use std::fs;
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicUsize, Ordering::Relaxed};
+use std::time::{Duration, Instant};
+
+#[cfg(feature = "jemalloc")]
+use jemallocator::Jemalloc as Underlying;
+#[cfg(not(feature = "jemalloc"))]
+use std::alloc::System as Underlying;
+
+/// The allocator underneath the counting one, chosen by the `jemalloc` feature.
+pub const ALLOCATOR: &str = if cfg!(feature = "jemalloc") {
+    "jemalloc"
+} else {
+    "system"
+};
+
+/// Bytes allocated right now, and the most there have been since `PEAK` was last reset.
+static CURRENT: AtomicUsize = AtomicUsize::new(0);
+static PEAK: AtomicUsize = AtomicUsize::new(0);
+
+/// Passes every allocation through to the underlying allocator, counting bytes on the way. Peaks
+/// are only measured in a binary that makes this its global allocator, as `aoc-bench` does.
+pub struct Counting;
+
+fn allocated(size: usize) {
+    let now = CURRENT.fetch_add(size, Relaxed) + size;
+    PEAK.fetch_max(now, Relaxed);
+}
+
+unsafe impl GlobalAlloc for Counting {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let p = Underlying.alloc(layout);
+        if !p.is_null() {
+            allocated(layout.size());
+        }
+        p
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let p = Underlying.alloc_zeroed(layout);
+        if !p.is_null() {
+            allocated(layout.size());
+        }
+        p
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        Underlying.dealloc(ptr, layout);
+        CURRENT.fetch_sub(layout.size(), Relaxed);
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let p = Underlying.realloc(ptr, layout, new_size);
+        if !p.is_null() {
+            // Count the new block before freeing the old: a moving realloc briefly holds both
+            allocated(new_size);
+            CURRENT.fetch_sub(layout.size(), Relaxed);
+        }
+        p
+    }
+}
+
+/// What to benchmark and how, from the command line.
+#[derive(Debug)]
+pub struct Bench {
+    pub days: Vec<u32>,
+    /// The most runs of each phase to take the median of.
+    pub samples: usize,
+    /// Threads for rayon's pool, or its own choice, one per core, for `None`.
+    threads: Option<usize>,
+    baseline: PathBuf,
+    /// Whether to overwrite the baseline with this run's results.
+    save: bool,
+    /// How much slower or hungrier than the baseline a phase can get, in percent, before it's
+    /// flagged.
+    threshold: f64,
+    /// How much a phase's time or peak has to grow by before it's flagged, however big that is
+    /// in percent, so phases that take nanoseconds or a few bytes aren't flagged for noise.
+    time_floor: Duration,
+    peak_floor: usize,
+}
+
+impl Bench {
+    pub fn from_args(mut args: impl Iterator<Item = String>) -> Result<Bench> {
+        let mut bench = Bench {
+            days: vec![],
+            samples: 10,
+            threads: None,
+            baseline: PathBuf::from("bench-baseline.txt"),
+            save: false,
+            threshold: 10.0,
+            time_floor: Duration::from_micros(100),
+            peak_floor: 4096,
+        };
+        while let Some(arg) = args.next() {
+            if !arg.starts_with("--") {
+                bench.days.push(crate::parse_day(&arg)?);
+                continue;
+            }
+            if arg == "--save" {
+                bench.save = true;
+                continue;
+            }
+            let value = args
+                .next()
+                .ok_or_else(|| anyhow!("{} needs a value\n{}", arg, crate::USAGE))?;
+            let number = |what| anyhow!("{} must be {}, got {:?}", arg, what, value);
+            match arg.as_str() {
+                "--samples" => {
+                    bench.samples = value
+                        .parse()
+                        .ok()
+                        .filter(|&n| n > 0)
+                        .ok_or_else(|| number("a positive number"))?
+                }
+                "--time-floor" => {
+                    bench.time_floor =
+                        Duration::from_micros(value.parse().map_err(|_| number("a number of µs"))?)
+                }
+                "--peak-floor" => {
+                    bench.peak_floor = value.parse().map_err(|_| number("a number of bytes"))?
+                }
+                "--threads" => {
+                    bench.threads = Some(
+                        value
+                            .parse()
+                            .ok()
+                            .filter(|&n| n > 0)
+                            .ok_or_else(|| number("a positive number"))?,
+                    )
+                }
+                "--threshold" => {
+                    bench.threshold = value
+                        .parse()
+                        .ok()
+                        .filter(|&t: &f64| t >= 0.0)
+                        .ok_or_else(|| number("a percentage"))?
+                }
+                "--baseline" => bench.baseline = PathBuf::from(value),
+                _ => bail!("unknown option {:?}\n{}", arg, crate::USAGE),
+            }
+        }
+        if bench.days.is_empty() {
+            bench.days = (1..=25).collect();
+        }
+        Ok(bench)
+    }
+}
+
+/// How long a phase took and the most memory it had allocated on top of what was there before.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Measurement {
+    pub median: Duration,
+    pub peak: usize,
+}
+
+/// The phases of a day, in the order they're measured and reported.
+const PHASES: [&str; 3] = ["parse", "part1", "part2"];
+
+/// Once a phase has run for this long it stops taking samples, so the slow days finish.
+const BUDGET: Duration = Duration::from_secs(5);
+
+/// Runs `f` up to `samples` times, at least once but no more once `BUDGET` is spent.
+fn sample<T>(samples: usize, mut f: impl FnMut() -> T) -> Measurement {
+    let started = Instant::now();
+    let mut times = Vec::with_capacity(samples);
+    let mut peak = 0;
+    while times.len() < samples && (times.is_empty() || started.elapsed() < BUDGET) {
+        let before = CURRENT.load(Relaxed);
+        PEAK.store(before, Relaxed);
+        let start = Instant::now();
+        let out = f();
+        times.push(start.elapsed());
+        peak = peak.max(PEAK.load(Relaxed).saturating_sub(before));
+        drop(out);
+    }
+    times.sort();
+    Measurement {
+        median: times[times.len() / 2],
+        peak,
+    }
+}
+
+/// Measures parsing `input` and answering each part from it, in the order of `PHASES`.
+pub fn measure<S: Solution>(input: &str, samples: usize) -> Result<[Measurement; 3]> {
+    let parsed = S::parse(input)?;
+    Ok([
+        sample(samples, || S::parse(input)),
+        sample(samples, || S::part1(&parsed)),
+        sample(samples, || S::part2(&parsed)),
+    ])
+}
+
+/// Measurements saved by an earlier run, by day and phase, and the setup they were taken with.
+#[derive(Debug, Default, PartialEq)]
+struct Baseline {
+    setup: String,
+    measurements: HashMap<(u32, String), Measurement>,
+}
+
+impl Baseline {
+    /// Reads back what `Display` writes: a `#` line naming the setup, then a line of day, phase,
+    /// median nanoseconds and peak bytes for each measurement.
+    fn parse(s: &str) -> Result<Baseline> {
+        let mut baseline = Baseline::default();
+        for (i, line) in s.lines().enumerate() {
+            if let Some(setup) = line.strip_prefix('#') {
+                baseline.setup = setup.trim().to_string();
+                continue;
+            }
+            let fields: Vec<&str> = line.split_whitespace().collect();
+            let bad = || anyhow!("line {}, expected day, phase, nanoseconds and bytes", i + 1);
+            let [day, phase, nanos, peak] = fields[..] else {
+                return Err(bad());
+            };
+            let measurement = Measurement {
+                median: Duration::from_nanos(nanos.parse().map_err(|_| bad())?),
+                peak: peak.parse().map_err(|_| bad())?,
+            };
+            let day = day.parse().map_err(|_| bad())?;
+            baseline
+                .measurements
+                .insert((day, phase.to_string()), measurement);
+        }
+        Ok(baseline)
+    }
+}
+
+impl Display for Baseline {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let mut keys: Vec<_> = self.measurements.keys().collect();
+        keys.sort_by_key(|(day, phase)| (*day, PHASES.iter().position(|p| p == phase)));
+        writeln!(f, "# {}", self.setup)?;
+        for key in keys {
+            let m = &self.measurements[key];
+            writeln!(f, "{} {} {} {}", key.0, key.1, m.median.as_nanos(), m.peak)?;
+        }
+        Ok(())
+    }
+}
+
+/// Whether `now` is worse than `then` by more than `threshold` percent and by more than `floor`.
+fn regressed(now: f64, then: f64, threshold: f64, floor: f64) -> bool {
+    change(now, then) > threshold && now - then > floor
+}
+
+/// How `now` compares with `then`, as a percentage change.
+fn change(now: f64, then: f64) -> f64 {
+    if then == 0.0 {
+        if now == 0.0 {
+            0.0
+        } else {
+            f64::INFINITY
+        }
+    } else {
+        (now / then - 1.0) * 100.0
+    }
+}
+
+fn bytes(n: usize) -> String {
+    const UNITS: [&str; 4] = ["B", "KiB", "MiB", "GiB"];
+    let mut size = n as f64;
+    let mut unit = 0;
+    while size >= 1024.0 && unit < UNITS.len() - 1 {
+        size /= 1024.0;
+        unit += 1;
+    }
+    if unit == 0 {
+        format!("{} B", n)
+    } else {
+        format!("{:.1} {}", size, UNITS[unit])
+    }
+}
+
+/// Benchmarks each of `bench.days`, with `measure` calling [`measure`] with the day's solution,
+/// and reports each phase as it's measured. Fails if any phase regressed against the baseline.
+pub fn run(
+    bench: &Bench,
+    mut measure: impl FnMut(u32, &str, usize) -> Result<[Measurement; 3]>,
+) -> Result<()> {
+    if let Some(threads) = bench.threads {
+        rayon::ThreadPoolBuilder::new()
+            .num_threads(threads)
+            .build_global()
+            .context("setting up rayon's threads")?;
+    }
+    let setup = format!(
+        "allocator={} threads={}",
+        ALLOCATOR,
+        rayon::current_num_threads()
+    );
+    println!("{}, up to {} samples per phase", setup, bench.samples);
+
+    let old = match fs::read_to_string(&bench.baseline) {
+        Ok(s) => {
+            let old = Baseline::parse(&s)
+                .with_context(|| format!("reading baseline {}", bench.baseline.display()))?;
+            if old.setup == setup {
+                println!("comparing with {}", bench.baseline.display());
+            } else {
+                // A different allocator or thread count changes what's measured, so differences
+                // are shown for comparing the setups but aren't regressions
+                println!(
+                    "warning: {} was recorded with {}, so nothing is flagged as regressed",
+                    bench.baseline.display(),
+                    old.setup
+                );
+            }
+            old
+        }
+        Err(_) => {
+            println!(
+                "no baseline at {} to compare with",
+                bench.baseline.display()
+            );
+            Baseline::default()
+        }
+    };
+
+    let comparable = old.setup == setup;
+    let mut new = Baseline {
+        setup,
+        measurements: HashMap::new(),
+    };
+    let mut regressions = 0;
+    for &day in &bench.days {
+        let input = aoc::read_input(day, None)?;
+        let measurements = measure(day, &input, bench.samples)?;
+        for (phase, m) in PHASES.iter().zip(measurements) {
+            let key = (day, phase.to_string());
+            let mut line = format!(
+                "day {:>2} {}  {:>10}  {:>10}",
+                day,
+                phase,
+                format!("{:.1?}", m.median),
+                bytes(m.peak)
+            );
+            if let Some(then) = old.measurements.get(&key) {
+                let time = change(m.median.as_secs_f64(), then.median.as_secs_f64());
+                let peak = change(m.peak as f64, then.peak as f64);
+                write!(line, "  time {:+6.1}%  peak {:+6.1}%", time, peak).unwrap();
+                let slower = regressed(
+                    m.median.as_secs_f64(),
+                    then.median.as_secs_f64(),
+                    bench.threshold,
+                    bench.time_floor.as_secs_f64(),
+                );
+                let hungrier = regressed(
+                    m.peak as f64,
+                    then.peak as f64,
+                    bench.threshold,
+                    bench.peak_floor as f64,
+                );
+                if comparable && (slower || hungrier) {
+                    line.push_str("  REGRESSED");
+                    regressions += 1;
+                }
+            }
+            println!("{}", line);
+            new.measurements.insert(key, m);
+        }
+    }
+
+    if bench.save {
+        // Keep the baseline's other days, so benchmarking one day doesn't lose the rest
+        let mut saved = old;
+        saved.setup = new.setup;
+        saved.measurements.extend(new.measurements);
+        fs::write(&bench.baseline, saved.to_string())
+            .with_context(|| format!("saving baseline {}", bench.baseline.display()))?;
+        println!("saved baseline {}", bench.baseline.display());
+    }
+    if regressions > 0 {
+        bail!(
+            "{} phases regressed by more than {}%",
+            regressions,
+            bench.threshold
+        );
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{bytes, change, regressed, Baseline, Bench, Measurement};
+    use std::time::Duration;
+
+    fn args(s: &str) -> impl Iterator<Item = String> + '_ {
+        s.split_whitespace().map(String::from)
+    }
+
+    #[test]
+    fn options() {
+        let bench = Bench::from_args(args("3 14 --samples 5 --save --threads 2")).unwrap();
+        assert_eq!(vec![3, 14], bench.days);
+        assert_eq!(5, bench.samples);
+        assert_eq!(Some(2), bench.threads);
+        assert!(bench.save);
+        assert_eq!(Duration::from_micros(100), bench.time_floor);
+
+        let bench = Bench::from_args(args("25 --time-floor 5 --peak-floor 0")).unwrap();
+        assert_eq!(Duration::from_micros(5), bench.time_floor);
+        assert_eq!(0, bench.peak_floor);
+
+        assert_eq!(25, Bench::from_args(args("")).unwrap().days.len());
+        assert!(Bench::from_args(args("26")).is_err());
+        assert!(Bench::from_args(args("--samples 0")).is_err());
+        assert!(Bench::from_args(args("--threshold")).is_err());
+    }
+
+    #[test]
+    fn baselines_round_trip() {
+        let mut baseline = Baseline {
+            setup: "allocator=system threads=1".to_string(),
+            ..Baseline::default()
+        };
+        for (day, phase, nanos, peak) in [(2, "part2", 7, 0), (2, "parse", 1500, 4096)] {
+            let m = Measurement {
+                median: Duration::from_nanos(nanos),
+                peak,
+            };
+            baseline.measurements.insert((day, phase.to_string()), m);
+        }
+        let saved = baseline.to_string();
+        assert_eq!(
+            "# allocator=system threads=1\n2 parse 1500 4096\n2 part2 7 0\n",
+            saved
+        );
+        assert_eq!(baseline, Baseline::parse(&saved).unwrap());
+        assert!(Baseline::parse("2 parse fast 0\n").is_err());
+    }
+
+    #[test]
+    fn changes_and_sizes() {
+        assert_eq!(50.0, change(3.0, 2.0));
+        assert_eq!(-50.0, change(1.0, 2.0));
+        assert_eq!(0.0, change(0.0, 0.0));
+        assert_eq!(f64::INFINITY, change(1.0, 0.0));
+        // Twice as slow, but by only 40ns
+        assert!(!regressed(80e-9, 40e-9, 10.0, 1e-4));
+        assert!(regressed(0.5, 0.2, 10.0, 1e-4));
+        assert!(!regressed(0.5, 0.2, 200.0, 1e-4));
+        assert_eq!("512 B", bytes(512));
+        assert_eq!("1.5 KiB", bytes(1536));
+        assert_eq!("3.0 MiB", bytes(3 << 20));
+    }
+}
@@ -0,0 +1,13 @@
+use anyhow::Result;
+use aoc_cli::bench::{self, measure, Bench, Counting};
+use aoc_cli::by_day;
+
+#[global_allocator]
+static GLOBAL: Counting = Counting;
+
+fn main() -> Result<()> {
+    let bench = Bench::from_args(std::env::args().skip(1))?;
+    bench::run(&bench, |day, input, samples| {
+        by_day!(day, measure(input, samples))
+    })
+}
@@ -0,0 +1,124 @@
+//! `aoc draw`: pictures of a day's puzzle, for the days that can draw themselves. Those days have
+//! a `draw` function giving the frames of an animation, which is often a single frame.
+
+use anyhow::{anyhow, bail, Context, Result};
+use aoc::Solution;
+use render::Picture;
+use std::path::PathBuf;
+use std::time::Duration;
+
+/// What to draw and where, from the command line. With no files to write, the last frame goes to
+/// the terminal.
+#[derive(Debug)]
+pub struct Draw {
+    pub day: u32,
+    input: Option<String>,
+    /// Where to write the last frame as a PNG.
+    png: Option<PathBuf>,
+    /// Where to write every frame as an animated GIF.
+    gif: Option<PathBuf>,
+    /// How many pixels across each cell takes up in the files.
+    scale: usize,
+    /// How long each frame of a GIF shows for.
+    delay: Duration,
+}
+
+impl Draw {
+    pub fn from_args(mut args: impl Iterator<Item = String>) -> Result<Draw> {
+        let day = crate::parse_day(&args.next().ok_or_else(|| anyhow!(crate::USAGE))?)?;
+
+        let mut draw = Draw {
+            day,
+            input: None,
+            png: None,
+            gif: None,
+            scale: 4,
+            delay: Duration::from_millis(100),
+        };
+        while let Some(flag) = args.next() {
+            let value = args
+                .next()
+                .ok_or_else(|| anyhow!("{} needs a value\n{}", flag, crate::USAGE))?;
+            match flag.as_str() {
+                "--input" => draw.input = Some(value),
+                "--png" => draw.png = Some(PathBuf::from(value)),
+                "--gif" => draw.gif = Some(PathBuf::from(value)),
+                "--scale" => draw.scale = positive(&flag, &value)?,
+                "--delay" => draw.delay = Duration::from_millis(positive(&flag, &value)? as u64),
+                _ => bail!("unknown option {:?}\n{}", flag, crate::USAGE),
+            }
+        }
+        Ok(draw)
+    }
+}
+
+fn positive(flag: &str, value: &str) -> Result<usize> {
+    value
+        .parse()
+        .ok()
+        .filter(|&n| n > 0)
+        .ok_or_else(|| anyhow!("{} must be a positive number, got {:?}", flag, value))
+}
+
+/// Parses `input` for `S` and draws it with `draw`.
+fn frames<S: Solution>(input: &str, draw: fn(&S::Input) -> Vec<Picture>) -> Result<Vec<Picture>> {
+    Ok(draw(&S::parse(input)?))
+}
+
+pub fn run(draw: &Draw) -> Result<()> {
+    let input = aoc::read_input(draw.day, draw.input.as_deref())?;
+    let frames = match draw.day {
+        11 => frames::<day11::Day11>(&input, day11::draw)?,
+        13 => frames::<day13::Day13>(&input, day13::draw)?,
+        15 => frames::<day15::Day15>(&input, day15::draw)?,
+        20 => frames::<day20::Day20>(&input, day20::draw)?,
+        25 => frames::<day25::Day25>(&input, day25::draw)?,
+        day => bail!(
+            "day {} has nothing to draw; days 11, 13, 15, 20 and 25 do",
+            day
+        ),
+    };
+    let last = frames.last().context("nothing was drawn")?;
+
+    if draw.png.is_none() && draw.gif.is_none() {
+        print!("{}", last.ansi());
+    }
+    if let Some(path) = &draw.png {
+        last.scaled(draw.scale)
+            .write_png(path)
+            .with_context(|| format!("writing {}", path.display()))?;
+        println!("wrote {}", path.display());
+    }
+    if let Some(path) = &draw.gif {
+        let frames: Vec<Picture> = frames.iter().map(|f| f.scaled(draw.scale)).collect();
+        render::write_gif(path, &frames, draw.delay)
+            .with_context(|| format!("writing {}", path.display()))?;
+        println!("wrote {} frames to {}", frames.len(), path.display());
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Draw;
+    use std::path::PathBuf;
+    use std::time::Duration;
+
+    fn args(s: &str) -> impl Iterator<Item = String> + '_ {
+        s.split_whitespace().map(String::from)
+    }
+
+    #[test]
+    fn options() {
+        let draw = Draw::from_args(args("25 --gif herd.gif --scale 2 --delay 40")).unwrap();
+        assert_eq!(25, draw.day);
+        assert_eq!(Some(PathBuf::from("herd.gif")), draw.gif);
+        assert_eq!(None, draw.png);
+        assert_eq!(2, draw.scale);
+        assert_eq!(Duration::from_millis(40), draw.delay);
+
+        assert!(Draw::from_args(args("")).is_err());
+        assert!(Draw::from_args(args("15 --scale 0")).is_err());
+        assert!(Draw::from_args(args("15 --png")).is_err());
+    }
+}
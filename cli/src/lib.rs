@@ -0,0 +1,46 @@
+//! What the `aoc` and `aoc-bench` binaries share: their subcommands, and dispatch from a day's
+//! number to its solution.
+//!
+//! Benchmarking has a binary of its own so that only it runs on the allocation-counting
+//! allocator, and `aoc` runs days on the plain one.
+
+pub mod bench;
+pub mod draw;
+pub mod new_day;
+
+use anyhow::{anyhow, Result};
+
+pub const USAGE: &str = "usage: aoc run <day> [--part 1|2] [--input <path>|-] [--json]
+       aoc draw <day> [--input <path>|-] [--png <path>] [--gif <path>] [--scale <n>]
+                [--delay <ms>]
+       aoc new-day <day>
+       aoc-bench [<day>...] [--samples <n>] [--threads <n>] [--baseline <path>] [--save]
+                 [--threshold <percent>] [--time-floor <µs>] [--peak-floor <bytes>]";
+
+pub fn parse_day(day: &str) -> Result<u32> {
+    day.parse()
+        .ok()
+        .filter(|d| (1..=25).contains(d))
+        .ok_or_else(|| anyhow!("day must be 1 to 25, got {:?}", day))
+}
+
+/// Calls the generic function `$f` with the solution for `$day`, passing it `$args`.
+#[macro_export]
+macro_rules! by_day {
+    ($day:expr, $f:ident $args:tt) => {
+        $crate::by_day!(@ $day, $f $args,
+            1 => day01::Day01, 2 => day02::Day02, 3 => day03::Day03, 4 => day04::Day04,
+            5 => day05::Day05, 6 => day06::Day06, 7 => day07::Day07, 8 => day08::Day08,
+            9 => day09::Day09, 10 => day10::Day10, 11 => day11::Day11, 12 => day12::Day12,
+            13 => day13::Day13, 14 => day14::Day14, 15 => day15::Day15, 16 => day16::Day16,
+            17 => day17::Day17, 18 => day18::Day18, 19 => day19::Day19, 20 => day20::Day20,
+            21 => day21::Day21, 22 => day22::Day22, 23 => day23::Day23, 24 => day24::Day24,
+            25 => day25::Day25)
+    };
+    (@ $day:expr, $f:ident $args:tt, $($n:literal => $s:ty),*) => {
+        match $day {
+            $($n => $f::<$s> $args,)*
+            day => unreachable!("day {} was checked when parsing", day),
+        }
+    };
+}
@@ -0,0 +1,102 @@
+use anyhow::{anyhow, bail, Result};
+use aoc::records;
+use aoc_cli::draw::{self, Draw};
+use aoc_cli::{by_day, new_day, parse_day, USAGE};
+
+#[cfg(feature = "jemalloc")]
+#[global_allocator]
+static GLOBAL: jemallocator::Jemalloc = jemallocator::Jemalloc;
+
+/// What to do, from the command line.
+#[derive(Debug)]
+enum Command {
+    Run(Run),
+    Draw(Draw),
+    NewDay(u32),
+}
+
+impl Command {
+    fn from_args(mut args: impl Iterator<Item = String>) -> Result<Command> {
+        match args.next().as_deref() {
+            Some("run") => Ok(Command::Run(Run::from_args(args)?)),
+            Some("bench") => bail!("benchmarks run with aoc-bench\n{}", USAGE),
+            Some("draw") => Ok(Command::Draw(Draw::from_args(args)?)),
+            Some("new-day") => match (args.next(), args.next()) {
+                (Some(day), None) => Ok(Command::NewDay(parse_day(&day)?)),
+                _ => bail!(USAGE),
+            },
+            Some(cmd) => bail!("unknown command {:?}\n{}", cmd, USAGE),
+            None => bail!(USAGE),
+        }
+    }
+}
+
+/// What to run.
+#[derive(Debug)]
+struct Run {
+    day: u32,
+    part: Option<u32>,
+    input: Option<String>,
+    /// Whether to print each answer as a line of JSON.
+    json: bool,
+}
+
+impl Run {
+    fn from_args(mut args: impl Iterator<Item = String>) -> Result<Run> {
+        let day = parse_day(&args.next().ok_or_else(|| anyhow!(USAGE))?)?;
+
+        let mut run = Run {
+            day,
+            part: None,
+            input: None,
+            json: false,
+        };
+        while let Some(flag) = args.next() {
+            if flag == "--json" {
+                run.json = true;
+                continue;
+            }
+            let value = args
+                .next()
+                .ok_or_else(|| anyhow!("{} needs a value\n{}", flag, USAGE))?;
+            match flag.as_str() {
+                "--part" => match value.as_str() {
+                    "1" => run.part = Some(1),
+                    "2" => run.part = Some(2),
+                    _ => bail!("part must be 1 or 2, got {:?}", value),
+                },
+                "--input" => run.input = Some(value),
+                _ => bail!("unknown option {:?}\n{}", flag, USAGE),
+            }
+        }
+        Ok(run)
+    }
+}
+
+fn main() -> Result<()> {
+    match Command::from_args(std::env::args().skip(1))? {
+        Command::Run(run) => {
+            let input = aoc::read_input(run.day, run.input.as_deref())?;
+            for record in by_day!(run.day, records(&input, run.part))? {
+                if run.json {
+                    println!("{}", record.to_json());
+                } else {
+                    aoc::print_answer(record.part, &record.answer);
+                }
+            }
+            Ok(())
+        }
+        Command::Draw(draw) => draw::run(&draw),
+        Command::NewDay(day) => {
+            for file in new_day::create(new_day::workspace_root(), day)? {
+                println!("created {}", file.display());
+            }
+            println!(
+                "day{:02} is a workspace member; {}",
+                day,
+                new_day::wiring(day)
+            );
+            Ok(())
+        }
+    }
+}
@@ -0,0 +1,191 @@
+//! `aoc new-day`: starts a day's crate from the `template` crate.
+//!
+//! The template is a working crate for the placeholder day 0: `day00` and `Day00` in its files
+//! become the new day's names, and its `DAY` becomes the new day's number.
+
+use anyhow::{bail, Context, Result};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// The workspace this binary was built in, where new days go.
+pub fn workspace_root() -> &'static Path {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .parent()
+        .expect("cli crate lives in the workspace")
+}
+
+/// Creates `dayNN` for `day` in the workspace at `root`, and an empty input file for it if
+/// there isn't one, returning the files created.
+pub fn create(root: &Path, day: u32) -> Result<Vec<PathBuf>> {
+    let name = format!("day{:02}", day);
+    let dir = root.join(&name);
+    if dir.exists() {
+        bail!("{} already exists", dir.display());
+    }
+    let manifest = root.join("Cargo.toml");
+    let workspace =
+        fs::read_to_string(&manifest).with_context(|| format!("reading {}", manifest.display()))?;
+    if !members(&workspace).iter().any(|m| glob_matches(m, &name)) {
+        bail!(
+            "no workspace member in {} matches {}, so it wouldn't be built",
+            manifest.display(),
+            name
+        );
+    }
+
+    let mut created = vec![];
+    copy_template(&root.join("template"), &dir, day, &mut created)?;
+    let input = root.join(format!("inputs/{}.txt", name));
+    if !input.exists() {
+        fs::create_dir_all(input.parent().unwrap())?;
+        fs::write(&input, "").with_context(|| format!("creating {}", input.display()))?;
+        created.push(input);
+    }
+    Ok(created)
+}
+
+/// What to add to the `aoc` binary so that `aoc run` can run `day`.
+pub fn wiring(day: u32) -> String {
+    let name = format!("day{:02}", day);
+    format!(
+        "to run it with `aoc run`, add to [dependencies] in cli/Cargo.toml:\n    \
+         {name} = {{path = \"../{name}\" }}\n\
+         and to the list in by_day! in cli/src/lib.rs:\n    \
+         {day} => {name}::Day{day:02}",
+    )
+}
+
+/// Copies every file under `from` to the same place under `to`, renamed for `day`.
+fn copy_template(from: &Path, to: &Path, day: u32, created: &mut Vec<PathBuf>) -> Result<()> {
+    fs::create_dir_all(to).with_context(|| format!("creating {}", to.display()))?;
+    let entries = fs::read_dir(from).with_context(|| format!("reading {}", from.display()))?;
+    for entry in entries {
+        let entry = entry?;
+        let target = to.join(entry.file_name());
+        if entry.file_type()?.is_dir() {
+            copy_template(&entry.path(), &target, day, created)?;
+        } else {
+            let text = fs::read_to_string(entry.path())
+                .with_context(|| format!("reading {}", entry.path().display()))?;
+            fs::write(&target, fill_in(&text, day))
+                .with_context(|| format!("writing {}", target.display()))?;
+            created.push(target);
+        }
+    }
+    Ok(())
+}
+
+/// The template's `text` with its placeholder day replaced by `day`.
+fn fill_in(text: &str, day: u32) -> String {
+    text.replace("day00", &format!("day{:02}", day))
+        .replace("Day00", &format!("Day{:02}", day))
+        .replace("const DAY: u32 = 0;", &format!("const DAY: u32 = {};", day))
+}
+
+/// The `members` patterns of a workspace's `Cargo.toml`.
+fn members(manifest: &str) -> Vec<&str> {
+    let Some(start) = manifest.find("members") else {
+        return vec![];
+    };
+    let list = &manifest[start..];
+    let list = &list[list.find('[').map_or(0, |i| i + 1)..list.find(']').unwrap_or(0)];
+    list.split(',')
+        .map(|m| m.trim().trim_matches('"'))
+        .filter(|m| !m.is_empty())
+        .collect()
+}
+
+/// Whether `name` matches `pattern`, where `*` matches any run of characters.
+fn glob_matches(pattern: &str, name: &str) -> bool {
+    match pattern.split_once('*') {
+        None => pattern == name,
+        Some((prefix, rest)) => {
+            let Some(tail) = name.strip_prefix(prefix) else {
+                return false;
+            };
+            (0..=tail.len())
+                .filter(|&i| tail.is_char_boundary(i))
+                .any(|i| glob_matches(rest, &tail[i..]))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{create, glob_matches, members, wiring, workspace_root};
+    use std::fs;
+    use std::path::PathBuf;
+
+    /// A scratch workspace holding a copy of the real template.
+    fn scratch(name: &str, members: &str) -> PathBuf {
+        let root =
+            std::env::temp_dir().join(format!("aoc-new-day-{}-{}", name, std::process::id()));
+        let _ = fs::remove_dir_all(&root);
+        fs::create_dir_all(root.join("template/src")).unwrap();
+        for file in ["Cargo.toml", "src/lib.rs", "src/main.rs"] {
+            let from = workspace_root().join("template").join(file);
+            fs::copy(from, root.join("template").join(file)).unwrap();
+        }
+        fs::write(
+            root.join("Cargo.toml"),
+            format!("[workspace]\n\nmembers = [\n    {}\n]\n", members),
+        )
+        .unwrap();
+        root
+    }
+
+    #[test]
+    fn creates_a_day() {
+        let root = scratch("create", "\"cli\",\n    \"day*\"");
+        let created = create(&root, 7).unwrap();
+        assert_eq!(4, created.len());
+
+        let manifest = fs::read_to_string(root.join("day07/Cargo.toml")).unwrap();
+        assert!(manifest.contains("name = \"day07\""));
+        let lib = fs::read_to_string(root.join("day07/src/lib.rs")).unwrap();
+        assert!(lib.contains("pub struct Day07;"));
+        assert!(lib.contains("const DAY: u32 = 7;"));
+        assert!(lib.contains("fn example()"));
+        let main = fs::read_to_string(root.join("day07/src/main.rs")).unwrap();
+        assert!(main.contains("aoc::run::<day07::Day07>()"));
+        assert_eq!(
+            "",
+            fs::read_to_string(root.join("inputs/day07.txt")).unwrap()
+        );
+
+        // An existing day is left alone
+        fs::write(root.join("day07/src/lib.rs"), "solved").unwrap();
+        assert!(create(&root, 7).is_err());
+        assert_eq!(
+            "solved",
+            fs::read_to_string(root.join("day07/src/lib.rs")).unwrap()
+        );
+        fs::remove_dir_all(root).unwrap();
+    }
+
+    #[test]
+    fn only_creates_workspace_members() {
+        let root = scratch("members", "\"cli\"");
+        assert!(create(&root, 3).is_err());
+        assert!(!root.join("day03").exists());
+        fs::remove_dir_all(root).unwrap();
+    }
+
+    #[test]
+    fn wires_up_the_day() {
+        let wiring = wiring(7);
+        assert!(wiring.contains("cli/Cargo.toml:\n    day07 = {path = \"../day07\" }\n"));
+        assert!(wiring.contains("cli/src/lib.rs:\n    7 => day07::Day07"));
+    }
+
+    #[test]
+    fn globs() {
+        let manifest = fs::read_to_string(workspace_root().join("Cargo.toml")).unwrap();
+        assert!(members(&manifest).contains(&"day*"));
+        assert!(glob_matches("day*", "day07"));
+        assert!(glob_matches("*07", "day07"));
+        assert!(glob_matches("d*y*", "day07"));
+        assert!(!glob_matches("day*", "template"));
+        assert!(!glob_matches("day0", "day07"));
+    }
+}
@@ -0,0 +1,702 @@
+//! Known answers for every day, on its stored input and on the puzzle's worked examples, so a
+//! change anywhere that alters an answer fails here with what changed.
+
+use aoc::Solution;
+use std::fmt::Write;
+
+/// The input a known answer is for.
+enum Input {
+    /// The day's input in `inputs/dayNN.txt`.
+    Stored,
+    Example(&'static str),
+}
+
+/// What a day answers for one input. Some examples only come with an answer for one part.
+struct Known {
+    input: Input,
+    part1: Option<&'static str>,
+    part2: Option<&'static str>,
+}
+
+fn stored(part1: &'static str, part2: &'static str) -> Known {
+    Known {
+        input: Input::Stored,
+        part1: Some(part1),
+        part2: Some(part2),
+    }
+}
+
+fn example(input: &'static str, part1: &'static str, part2: &'static str) -> Known {
+    Known {
+        input: Input::Example(input),
+        part1: Some(part1),
+        part2: Some(part2),
+    }
+}
+
+fn example_part1(input: &'static str, part1: &'static str) -> Known {
+    Known {
+        part2: None,
+        ..example(input, part1, "")
+    }
+}
+
+fn example_part2(input: &'static str, part2: &'static str) -> Known {
+    Known {
+        part1: None,
+        ..example(input, "", part2)
+    }
+}
+
+/// Writes an answer on the rest of the line, or below it, indented, if it's drawn over several.
+fn write_answer(out: &mut String, label: &str, answer: &str) {
+    if answer.contains('\n') {
+        writeln!(out, "    {}:", label).unwrap();
+        for line in answer.lines() {
+            writeln!(out, "      {}", line).unwrap();
+        }
+    } else {
+        writeln!(out, "    {}: {}", label, answer).unwrap();
+    }
+}
+
+/// Runs `S` on every input in `known`, failing with every answer that differs.
+fn check<S: Solution>(known: &[Known]) {
+    let mut diffs = String::new();
+    for (i, k) in known.iter().enumerate() {
+        let (name, input) = match k.input {
+            Input::Stored => (
+                "the stored input".to_string(),
+                aoc::read_input(S::DAY, None).unwrap(),
+            ),
+            Input::Example(input) => (format!("example {}", i), input.to_string()),
+        };
+        let parsed = match S::parse(&input) {
+            Ok(parsed) => parsed,
+            Err(e) => {
+                writeln!(diffs, "  parsing {} failed: {:#}", name, e).unwrap();
+                continue;
+            }
+        };
+
+        let answers = [
+            (1, k.part1, k.part1.map(|_| S::part1(&parsed).to_string())),
+            (2, k.part2, k.part2.map(|_| S::part2(&parsed).to_string())),
+        ];
+        for (part, expected, actual) in answers {
+            if let (Some(expected), Some(actual)) = (expected, actual) {
+                if expected != actual {
+                    writeln!(diffs, "  part {} on {}", part, name).unwrap();
+                    write_answer(&mut diffs, "expected", expected);
+                    write_answer(&mut diffs, "  actual", &actual);
+                }
+            }
+        }
+    }
+    assert!(
+        diffs.is_empty(),
+        "day {} answers changed:\n{}",
+        S::DAY,
+        diffs
+    );
+}
+
+#[test]
+fn day01() {
+    check::<day01::Day01>(&[
+        stored("1532", "1571"),
+        example(
+            r#"199
+200
+208
+210
+200
+207
+240
+269
+260
+263"#,
+            "7",
+            "5",
+        ),
+    ]);
+}
+
+#[test]
+fn day02() {
+    check::<day02::Day02>(&[
+        stored("1660158", "1604592846"),
+        example(
+            r#"forward 5
+down 5
+forward 8
+up 3
+down 8
+forward 2"#,
+            "150",
+            "900",
+        ),
+    ]);
+}
+
+#[test]
+fn day03() {
+    check::<day03::Day03>(&[
+        stored("4174964", "4474944"),
+        example(
+            r#"00100
+11110
+10110
+10111
+10101
+01111
+00111
+11100
+10000
+11001
+00010
+01010"#,
+            "198",
+            "230",
+        ),
+    ]);
+}
+
+#[test]
+fn day04() {
+    check::<day04::Day04>(&[
+        stored("35670", "22704"),
+        example(
+            r#"7,4,9,5,11,17,23,2,0,14,21,24,10,16,13,6,15,25,12,22,18,20,8,19,3,26,1
+
+22 13 17 11  0
+ 8  2 23  4 24
+21  9 14 16  7
+ 6 10  3 18  5
+ 1 12 20 15 19
+
+ 3 15  0  2 22
+ 9 18 13 17  5
+19  8  7 25 23
+20 11 10 24  4
+14 21 16 12  6
+
+14 21 17 24  4
+10 16 15  9 19
+18  8 23 26 20
+22 11 13  6  5
+ 2  0 12  3  7"#,
+            "4512",
+            "1924",
+        ),
+    ]);
+}
+
+#[test]
+fn day05() {
+    check::<day05::Day05>(&[
+        stored("6572", "21466"),
+        example(
+            r#"0,9 -> 5,9
+8,0 -> 0,8
+9,4 -> 3,4
+2,2 -> 2,1
+7,0 -> 7,4
+6,4 -> 2,0
+0,9 -> 2,9
+3,4 -> 1,4
+0,0 -> 8,8
+5,5 -> 8,2"#,
+            "5",
+            "12",
+        ),
+    ]);
+}
+
+#[test]
+fn day06() {
+    check::<day06::Day06>(&[
+        stored("351092", "1595330616005"),
+        example(r#"3,4,3,1,2"#, "5934", "26984457539"),
+    ]);
+}
+
+#[test]
+fn day07() {
+    check::<day07::Day07>(&[
+        stored("344138", "94862124"),
+        example(r#"16,1,2,0,4,2,7,1,2,14"#, "37", "168"),
+    ]);
+}
+
+#[test]
+fn day08() {
+    check::<day08::Day08>(&[
+        stored("488", "1040429"),
+        example(
+            r#"be cfbegad cbdgef fgaecd cgeb fdcge agebfd fecdb fabcd edb | fdgacbe cefdb cefbgd gcbe
+edbfga begcd cbg gc gcadebf fbgde acbgfd abcde gfcbed gfec | fcgedb cgb dgebacf gc
+fgaebd cg bdaec gdafb agbcfd gdcbef bgcad gfac gcb cdgabef | cg cg fdcagb cbg
+fbegcd cbd adcefb dageb afcb bc aefdc ecdab fgdeca fcdbega | efabcd cedba gadfec cb
+aecbfdg fbg gf bafeg dbefa fcge gcbea fcaegb dgceab fcbdga | gecf egdcabf bgf bfgea
+fgeab ca afcebg bdacfeg cfaedg gcfdb baec bfadeg bafgc acf | gebdcfa ecba ca fadegcb
+dbcfg fgd bdegcaf fgec aegbdf ecdfab fbedc dacgb gdcebf gf | cefg dcbef fcge gbcadfe
+bdfegc cbegaf gecbf dfcage bdacg ed bedf ced adcbefg gebcd | ed bcgafe cdgba cbgef
+egadfb cdbfeg cegd fecab cgb gbdefca cg fgcdab egfdb bfceg | gbdfcae bgc cg cgb
+gcafb gcf dcaebfg ecagb gf abcdeg gaef cafbge fdbac fegbdc | fgae cfgab fg bagce"#,
+            "26",
+            "61229",
+        ),
+    ]);
+}
+
+#[test]
+fn day09() {
+    check::<day09::Day09>(&[
+        stored("566", "891684"),
+        example(
+            r#"2199943210
+3987894921
+9856789892
+8767896789
+9899965678"#,
+            "15",
+            "1134",
+        ),
+    ]);
+}
+
+#[test]
+fn day10() {
+    check::<day10::Day10>(&[
+        stored("364389", "2870201088"),
+        example(
+            r#"[({(<(())[]>[[{[]{<()<>>
+[(()[<>])]({[<{<<[]>>(
+{([(<{}[<>[]}>{[]{[(<()>
+(((({<>}<{<{<>}{[]{[]{}
+[[<[([]))<([[{}[[()]]]
+[{[{({}]{}}([{[{{{}}([]
+{<[[]]>}<{[{[{[]{()[[[]
+[<(<(<(<{}))><([]([]()
+<{([([[(<>()){}]>(<<{{
+<{([{{}}[<[[[<>{}]]]>[]]"#,
+            "26397",
+            "288957",
+        ),
+    ]);
+}
+
+#[test]
+fn day11() {
+    check::<day11::Day11>(&[
+        stored("1613", "510"),
+        example(
+            r#"5483143223
+2745854711
+5264556173
+6141336146
+6357385478
+4167524645
+2176841721
+6882881134
+4846848554
+5283751526"#,
+            "1656",
+            "195",
+        ),
+    ]);
+}
+
+#[test]
+fn day12() {
+    check::<day12::Day12>(&[
+        stored("3802", "99448"),
+        example(
+            r#"start-A
+start-b
+A-c
+A-b
+b-d
+A-end
+b-end"#,
+            "10",
+            "36",
+        ),
+    ]);
+}
+
+#[test]
+fn day13() {
+    check::<day13::Day13>(&[
+        stored(
+            "842",
+            concat!(
+                "###  #### #  # ###   ##    ## #### #  #\n",
+                "#  # #    # #  #  # #  #    #    # #  #\n",
+                "###  ###  ##   #  # #       #   #  #  #\n",
+                "#  # #    # #  ###  #       #  #   #  #\n",
+                "#  # #    # #  # #  #  # #  # #    #  #\n",
+                "###  #    #  # #  #  ##   ##  ####  ## ",
+            ),
+        ),
+        example(
+            r#"6,10
+0,14
+9,10
+0,3
+10,4
+4,11
+6,0
+6,12
+4,1
+0,13
+10,12
+3,4
+3,0
+8,4
+1,10
+2,14
+8,10
+9,0
+
+fold along y=7
+fold along x=5"#,
+            "17",
+            concat!("#####\n", "#   #\n", "#   #\n", "#   #\n", "#####",),
+        ),
+    ]);
+}
+
+#[test]
+fn day14() {
+    check::<day14::Day14>(&[
+        stored("2768", "2914365137499"),
+        example(
+            r#"NNCB
+
+CH -> B
+HH -> N
+CB -> H
+NH -> C
+HB -> C
+HC -> B
+HN -> C
+NN -> C
+BH -> H
+NC -> B
+NB -> B
+BN -> B
+BB -> N
+BC -> B
+CC -> N
+CN -> C"#,
+            "1588",
+            "2188189693529",
+        ),
+    ]);
+}
+
+#[test]
+fn day15() {
+    check::<day15::Day15>(&[
+        stored("441", "2849"),
+        example(
+            r#"1163751742
+1381373672
+2136511328
+3694931569
+7463417111
+1319128137
+1359912421
+3125421639
+1293138521
+2311944581"#,
+            "40",
+            "315",
+        ),
+    ]);
+}
+
+#[test]
+fn day16() {
+    check::<day16::Day16>(&[
+        stored("923", "258888628940"),
+        example_part1("8A004A801A8002F478", "16"),
+        example_part1("620080001611562C8802118E34", "12"),
+        example_part1("C0015000016115A2E0802F182340", "23"),
+        example_part1("A0016C880162017C3686B18A3D4780", "31"),
+        example_part2("C200B40A82", "3"),
+        example_part2("04005AC33890", "54"),
+        example_part2("880086C3E88112", "7"),
+        example_part2("CE00C43D881120", "9"),
+        example_part2("D8005AC2A8F0", "1"),
+        example_part2("F600BC2D8F", "0"),
+        example_part2("9C005AC2F8F0", "0"),
+        example_part2("9C0141080250320F1802104A08", "1"),
+    ]);
+}
+
+#[test]
+fn day17() {
+    check::<day17::Day17>(&[
+        stored("4656", "1908"),
+        example(r#"target area: x=20..30, y=-10..-5"#, "45", "112"),
+    ]);
+}
+
+#[test]
+fn day18() {
+    check::<day18::Day18>(&[
+        stored("4111", "4917"),
+        example(
+            r#"[[[0,[5,8]],[[1,7],[9,6]]],[[4,[1,2]],[[1,4],2]]]
+[[[5,[2,8]],4],[5,[[9,9],0]]]
+[6,[[[6,2],[5,6]],[[7,6],[4,7]]]]
+[[[6,[0,7]],[0,9]],[4,[9,[9,0]]]]
+[[[7,[6,4]],[3,[1,3]]],[[[5,5],1],9]]
+[[6,[[7,3],[3,2]]],[[[3,8],[5,7]],4]]
+[[[[5,4],[7,7]],8],[[8,3],8]]
+[[9,3],[[9,9],[6,[4,9]]]]
+[[2,[[7,7],7]],[[5,8],[[9,3],[0,2]]]]
+[[[[5,2],5],[8,[3,7]]],[[5,[7,5]],[4,4]]]"#,
+            "4140",
+            "3993",
+        ),
+    ]);
+}
+
+#[test]
+fn day19() {
+    check::<day19::Day19>(&[
+        stored("462", "12158"),
+        example(
+            r#"--- scanner 0 ---
+404,-588,-901
+528,-643,409
+-838,591,734
+390,-675,-793
+-537,-823,-458
+-485,-357,347
+-345,-311,381
+-661,-816,-575
+-876,649,763
+-618,-824,-621
+553,345,-567
+474,580,667
+-447,-329,318
+-584,868,-557
+544,-627,-890
+564,392,-477
+455,729,728
+-892,524,684
+-689,845,-530
+423,-701,434
+7,-33,-71
+630,319,-379
+443,580,662
+-789,900,-551
+459,-707,401
+
+--- scanner 1 ---
+686,422,578
+605,423,415
+515,917,-361
+-336,658,858
+95,138,22
+-476,619,847
+-340,-569,-846
+567,-361,727
+-460,603,-452
+669,-402,600
+729,430,532
+-500,-761,534
+-322,571,750
+-466,-666,-811
+-429,-592,574
+-355,545,-477
+703,-491,-529
+-328,-685,520
+413,935,-424
+-391,539,-444
+586,-435,557
+-364,-763,-893
+807,-499,-711
+755,-354,-619
+553,889,-390
+
+--- scanner 2 ---
+649,640,665
+682,-795,504
+-784,533,-524
+-644,584,-595
+-588,-843,648
+-30,6,44
+-674,560,763
+500,723,-460
+609,671,-379
+-555,-800,653
+-675,-892,-343
+697,-426,-610
+578,704,681
+493,664,-388
+-671,-858,530
+-667,343,800
+571,-461,-707
+-138,-166,112
+-889,563,-600
+646,-828,498
+640,759,510
+-630,509,768
+-681,-892,-333
+673,-379,-804
+-742,-814,-386
+577,-820,562
+
+--- scanner 3 ---
+-589,542,597
+605,-692,669
+-500,565,-823
+-660,373,557
+-458,-679,-417
+-488,449,543
+-626,468,-788
+338,-750,-386
+528,-832,-391
+562,-778,733
+-938,-730,414
+543,643,-506
+-524,371,-870
+407,773,750
+-104,29,83
+378,-903,-323
+-778,-728,485
+426,699,580
+-438,-605,-362
+-469,-447,-387
+509,732,623
+647,635,-688
+-868,-804,481
+614,-800,639
+595,780,-596
+
+--- scanner 4 ---
+727,592,562
+-293,-554,779
+441,611,-461
+-714,465,-776
+-743,427,-804
+-660,-479,-426
+832,-632,460
+927,-485,-438
+408,393,-506
+466,436,-512
+110,16,151
+-258,-428,682
+-393,719,612
+-211,-452,876
+808,-476,-593
+-575,615,604
+-485,667,467
+-680,325,-822
+-627,-443,-432
+872,-547,-609
+833,512,582
+807,604,487
+839,-516,451
+891,-625,532
+-652,-548,-490
+30,-46,-14"#,
+            "79",
+            "3621",
+        ),
+    ]);
+}
+
+#[test]
+fn day20() {
+    check::<day20::Day20>(&[
+        stored("5326", "17096"),
+        example(
+            r#"..#.#..#####.#.#.#.###.##.....###.##.#..###.####..#####..#....#..#..##..###..######.###...####..#..#####..##..#.#####...##.#.#..#.##..#.#......#.###.######.###.####...#.##.##..#..#..#####.....#.#....###..#.##......#.....#..#..#..##..#...##.######.####.####.#.#...#.......#..#.#.#...####.##.#......#..#...##.#.##..#...##.#.##..###.#......#.#.......#.#.#.####.###.##...#.....####.#..#..#.##.#....##..#.####....##...##..#...#......#.#.......#.......##..####..#...#.#.#...##..#.#..###..#####........#..####......#..#
+
+#..#.
+#....
+##..#
+..#..
+..###"#,
+            "35",
+            "3351",
+        ),
+    ]);
+}
+
+#[test]
+fn day21() {
+    check::<day21::Day21>(&[
+        stored("512442", "346642902541848"),
+        example(
+            r#"Player 1 starting position: 4
+Player 2 starting position: 8"#,
+            "739785",
+            "444356092776315",
+        ),
+    ]);
+}
+
+#[test]
+fn day22() {
+    check::<day22::Day22>(&[
+        stored("623748", "1227345351869476"),
+        example(
+            r#"on x=10..12,y=10..12,z=10..12
+on x=11..13,y=11..13,z=11..13
+off x=9..11,y=9..11,z=9..11
+on x=10..10,y=10..10,z=10..10"#,
+            "39",
+            "39",
+        ),
+    ]);
+}
+
+#[test]
+fn day23() {
+    check::<day23::Day23>(&[
+        stored("11516", "40272"),
+        example(
+            r#"#############
+#...........#
+###B#C#B#D###
+  #A#D#C#A#
+  #########"#,
+            "12521",
+            "44169",
+        ),
+    ]);
+}
+
+#[test]
+fn day24() {
+    // The puzzle has no worked example for the whole program
+    check::<day24::Day24>(&[stored("51939397989999", "11717131211195")]);
+}
+
+#[test]
+fn day25() {
+    check::<day25::Day25>(&[
+        stored("504", "no part 2"),
+        example(
+            r#"v...>>.vv>
+.vv>>.vv..
+>>.>v>...v
+>>v>>.>.v.
+v>v.vv.v..
+>.>>..v...
+.vv..>.>v.
+v.v..>>v.v
+....v..v.>"#,
+            "58",
+            "no part 2",
+        ),
+    ]);
+}
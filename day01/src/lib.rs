@@ -0,0 +1,42 @@
+use anyhow::Result;
+use aoc::Solution;
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    const DAY: u32 = 1;
+    type Input = Vec<i32>;
+    type Part1 = i32;
+    type Part2 = i32;
+
+    fn parse(input: &str) -> Result<Vec<i32>> {
+        Ok(aoc::parse_lines(input)?)
+    }
+
+    fn part1(input: &Vec<i32>) -> i32 {
+        input.windows(2).filter(|xs| xs[0] < xs[1]).count() as i32
+    }
+
+    /// Neighbouring windows of three share two depths, so one sum is bigger than the last just
+    /// when the depth it gains is bigger than the one it loses.
+    fn part2(input: &Vec<i32>) -> i32 {
+        input.windows(4).filter(|xs| xs[0] < xs[3]).count() as i32
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::*;
+
+    #[test]
+    fn short_reports() {
+        for input in ["", "199\n", "199\n200\n208\n"] {
+            let depths = Day01::parse(input).unwrap();
+            assert_eq!(0, Day01::part2(&depths));
+        }
+        assert_eq!(0, Day01::part1(&Day01::parse("").unwrap()));
+        assert_eq!(1, Day01::part1(&Day01::parse("199\n200\n").unwrap()));
+        let err = Day01::parse("199\n2OO\n").unwrap_err();
+        assert!(err.to_string().starts_with("line 2, "));
+    }
+}
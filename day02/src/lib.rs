@@ -0,0 +1,159 @@
+use aoc::Solution;
+use std::str::FromStr;
+
+use anyhow::Result;
+use thiserror::Error;
+
+use crate::Motion::{DOWN, FORWARD, UP};
+
+#[derive(Debug, Eq, PartialEq)]
+pub enum Motion {
+    FORWARD(i32),
+    UP(i32),
+    DOWN(i32),
+}
+
+/// Why a line isn't a motion. Columns count from 1.
+#[derive(Clone, Debug, Eq, PartialEq, Error)]
+pub enum ParseMotionError {
+    #[error("column {column}: expected a direction and a distance")]
+    Missing { column: usize },
+    #[error("column {column}: unknown direction {token:?}")]
+    Direction { column: usize, token: String },
+    #[error("column {column}: distance is not a number: {token:?}")]
+    Distance { column: usize, token: String },
+    #[error("column {column}: unexpected {token:?} after the distance")]
+    Trailing { column: usize, token: String },
+}
+
+impl FromStr for Motion {
+    type Err = ParseMotionError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let missing = ParseMotionError::Missing {
+            column: aoc::end_column(s),
+        };
+        let mut words = aoc::words(s);
+        let (dir_column, dir) = words.next().ok_or(missing.clone())?;
+        let (dist_column, dist) = words.next().ok_or(missing)?;
+        if let Some((column, token)) = words.next() {
+            return Err(ParseMotionError::Trailing {
+                column,
+                token: token.to_string(),
+            });
+        }
+
+        let d: i32 = dist.parse().map_err(|_| ParseMotionError::Distance {
+            column: dist_column,
+            token: dist.to_string(),
+        })?;
+        match dir {
+            "forward" => Ok(FORWARD(d)),
+            "up" => Ok(UP(d)),
+            "down" => Ok(DOWN(d)),
+            _ => Err(ParseMotionError::Direction {
+                column: dir_column,
+                token: dir.to_string(),
+            }),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct Submarine {
+    depth: i32,
+    horiz: i32,
+    aim: i32,
+}
+
+impl Submarine {
+    fn new() -> Submarine {
+        Submarine {
+            depth: 0,
+            horiz: 0,
+            aim: 0,
+        }
+    }
+
+    fn step(&mut self, motion: &Motion) {
+        match motion {
+            FORWARD(d) => {
+                self.horiz += d;
+                self.depth += self.aim * d;
+            }
+            UP(d) => {
+                self.aim -= d;
+            }
+            DOWN(d) => {
+                self.aim += d;
+            }
+        }
+    }
+
+    fn follow(&mut self, motions: &[Motion]) {
+        for m in motions {
+            self.step(m);
+        }
+    }
+}
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    const DAY: u32 = 2;
+    type Input = Vec<Motion>;
+    type Part1 = i32;
+    type Part2 = i32;
+
+    fn parse(input: &str) -> Result<Vec<Motion>> {
+        Ok(aoc::parse_lines(input)?)
+    }
+
+    /// Under the part 1 rules `up` and `down` change the depth directly, which is exactly how they
+    /// change the aim under part 2's, so the aim doubles as the part 1 depth.
+    fn part1(input: &Vec<Motion>) -> i32 {
+        let mut sub = Submarine::new();
+        sub.follow(input);
+        sub.horiz * sub.aim
+    }
+
+    fn part2(input: &Vec<Motion>) -> i32 {
+        let mut sub = Submarine::new();
+        sub.follow(input);
+        sub.horiz * sub.depth
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::*;
+
+    #[test]
+    fn parse_errors() {
+        assert_eq!(Ok(DOWN(8)), "down 8".parse());
+        assert_eq!(
+            Err(ParseMotionError::Direction {
+                column: 1,
+                token: "back".to_string()
+            }),
+            "back 2".parse::<Motion>()
+        );
+        assert_eq!(
+            Err(ParseMotionError::Distance {
+                column: 4,
+                token: "3.5".to_string()
+            }),
+            "up 3.5".parse::<Motion>()
+        );
+        assert_eq!(
+            Err(ParseMotionError::Missing { column: 3 }),
+            "up".parse::<Motion>()
+        );
+
+        let err = Day02::parse("forward 5\nup 1 2\n").unwrap_err();
+        assert_eq!(
+            "line 2, column 6: unexpected \"2\" after the distance",
+            err.to_string()
+        );
+    }
+}
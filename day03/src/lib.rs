@@ -0,0 +1,179 @@
+use anyhow::Result;
+use aoc::Solution;
+use std::str::FromStr;
+use thiserror::Error;
+
+/// Why a line isn't a binary number. Columns count from 1.
+#[derive(Clone, Debug, Eq, PartialEq, Error)]
+pub enum ParseReadingError {
+    #[error("column {column}: expected 0 or 1, found {token:?}")]
+    Digit { column: usize, token: char },
+    #[error("column {column}: more than 32 bits")]
+    TooLong { column: usize },
+    #[error("column 1: expected a binary number")]
+    Empty,
+}
+
+/// One number from the report.
+struct Reading(u32);
+
+impl FromStr for Reading {
+    type Err = ParseReadingError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.is_empty() {
+            return Err(ParseReadingError::Empty);
+        }
+        let mut value: u32 = 0;
+        for (i, token) in s.chars().enumerate() {
+            let column = i + 1;
+            let bit = token
+                .to_digit(2)
+                .ok_or(ParseReadingError::Digit { column, token })?;
+            if i == u32::BITS as usize {
+                return Err(ParseReadingError::TooLong { column });
+            }
+            value = value << 1 | bit;
+        }
+        Ok(Reading(value))
+    }
+}
+
+/// The diagnostic report: the numbers and how many bits each has.
+#[derive(Debug)]
+pub struct Report {
+    bits: usize,
+    values: Vec<u32>,
+}
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    const DAY: u32 = 3;
+    type Input = Report;
+    type Part1 = u32;
+    type Part2 = u32;
+
+    fn parse(input: &str) -> Result<Report> {
+        let values = aoc::parse_lines::<Reading>(input)?
+            .into_iter()
+            .map(|r| r.0)
+            .collect();
+        let bits = input.lines().map(|s| s.len()).max().unwrap_or(0);
+        Ok(Report { bits, values })
+    }
+
+    fn part1(report: &Report) -> u32 {
+        let input = &report.values;
+        let mut one_counts: Vec<usize> = Vec::new();
+        for i in 0..report.bits {
+            let count = input.iter().filter(|x| *x & (1 << i) != 0).count();
+            if count > 0 {
+                one_counts.push(count);
+            } else {
+                break;
+            }
+        }
+        one_counts.reverse();
+
+        let threshold = input.len() / 2;
+        let mut gamma: u32 = 0;
+        let mut epsilon: u32 = 0;
+        for x in one_counts {
+            gamma <<= 1;
+            epsilon <<= 1;
+            if x > threshold {
+                gamma |= 1;
+            } else {
+                epsilon |= 1;
+            }
+        }
+
+        aoc::note("gamma", format!("{:01$b}", gamma, report.bits));
+        aoc::note("epsilon", format!("{:01$b}", epsilon, report.bits));
+        gamma * epsilon
+    }
+
+    fn part2(report: &Report) -> u32 {
+        let (bits, input) = (report.bits, &report.values);
+        let mut oxy = Vec::from_iter(input.iter().copied());
+        for i in 0..bits {
+            let pos = bits - 1 - i;
+            let ones_count = oxy.iter().filter(|x| *x & (1 << pos) != 0).count();
+            let non_zero = ones_count * 2 >= oxy.len();
+            oxy = oxy
+                .iter()
+                .filter(|x: &&u32| bit_match(**x, pos, non_zero))
+                .copied()
+                .collect();
+            if oxy.len() == 1 {
+                break;
+            }
+        }
+
+        let mut co2 = Vec::from_iter(input.iter().copied());
+        for i in 0..bits {
+            let pos = bits - 1 - i;
+            let ones_count = co2.iter().filter(|x| *x & (1 << pos) != 0).count();
+            let non_zero = ones_count * 2 < co2.len();
+            co2 = co2
+                .iter()
+                .filter(|x| bit_match(**x, pos, non_zero))
+                .copied()
+                .collect();
+            if co2.len() == 1 {
+                break;
+            }
+        }
+
+        // 4758400 is high
+        aoc::note("oxygen", format!("{:01$b}", oxy[0], bits));
+        aoc::note("co2", format!("{:01$b}", co2[0], bits));
+        oxy[0] * co2[0]
+    }
+}
+
+fn bit_match(x: u32, pos: usize, non_zero: bool) -> bool {
+    if non_zero {
+        x & (1 << pos) != 0
+    } else {
+        x & (1 << pos) == 0
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::*;
+    use aoc::LineError;
+
+    #[test]
+    fn parse_errors() {
+        let report = Day03::parse("00100\n11110\n").unwrap();
+        assert_eq!(5, report.bits);
+        assert_eq!(vec![0b00100, 0b11110], report.values);
+
+        let error = Day03::parse("00100\n11210").unwrap_err();
+        assert_eq!(
+            Some(&LineError {
+                line: 2,
+                error: ParseReadingError::Digit {
+                    column: 3,
+                    token: '2'
+                }
+            }),
+            error.downcast_ref()
+        );
+        assert_eq!(
+            "line 2, column 3: expected 0 or 1, found '2'",
+            error.to_string()
+        );
+        assert_eq!(
+            Err(ParseReadingError::TooLong { column: 33 }),
+            "1".repeat(33).parse::<Reading>().map(|r| r.0)
+        );
+        assert_eq!(
+            Err(ParseReadingError::Empty),
+            "".parse::<Reading>().map(|r| r.0)
+        );
+    }
+}
@@ -1,1095 +1,5 @@
 use anyhow::Result;
-use std::str::FromStr;
-
-#[derive(Debug)]
-struct Record;
-
-impl FromStr for Record {
-    type Err = anyhow::Error;
-
-    fn from_str(_s: &str) -> Result<Self, Self::Err> {
-        Ok(Record)
-    }
-}
 
 fn main() -> Result<()> {
-    let input: Vec<u32> = INPUT
-        .lines()
-        .map(|l| u32::from_str_radix(l, 2).unwrap())
-        .collect();
-    let bits = INPUT.lines().map(|s| s.len()).max().unwrap();
-    let mut one_counts: Vec<usize> = Vec::new();
-    for i in 0..bits {
-        let count = input.iter().filter(|x| *x & (1 << i) != 0).count();
-        if count > 0 {
-            one_counts.push(count);
-        } else {
-            break;
-        }
-    }
-    one_counts.reverse();
-
-    let threshold = input.len() / 2;
-    let mut gamma: u32 = 0;
-    let mut epsilon: u32 = 0;
-    for x in one_counts {
-        gamma = gamma << 1;
-        epsilon = epsilon << 1;
-        if x > threshold {
-            gamma = gamma | 1;
-        } else {
-            epsilon = epsilon | 1;
-        }
-    }
-
-    println!("gamma:   {:#014b}\nepsilon: {:#014b}", gamma, epsilon);
-    println!("Part1: {}", gamma * epsilon);
-
-    part2(bits, &input);
-    Ok(())
+    aoc::run::<day03::Day03>()
 }
-
-fn bit_match(x: u32, pos: usize, non_zero: bool) -> bool {
-    if non_zero {
-        x & (1 << pos) != 0
-    } else {
-        x & (1 << pos) == 0
-    }
-}
-
-fn part2(bits: usize, input: &[u32]) {
-    let mut oxy = Vec::from_iter(input.iter().copied());
-    for i in 0..bits {
-        let pos = bits - 1 - i;
-        let ones_count = oxy.iter().filter(|x| *x & (1 << pos) != 0).count();
-        let non_zero = ones_count * 2 >= oxy.len();
-        oxy = oxy
-            .iter()
-            .filter(|x: &&u32| bit_match(**x, pos, non_zero))
-            .copied()
-            .collect();
-        if oxy.len() == 1 {
-            break;
-        }
-    }
-
-    let mut co2 = Vec::from_iter(input.iter().copied());
-    for i in 0..bits {
-        let pos = bits - 1 - i;
-        let ones_count = co2.iter().filter(|x| *x & (1 << pos) != 0).count();
-        let non_zero = ones_count * 2 < co2.len();
-        co2 = co2
-            .iter()
-            .filter(|x| bit_match(**x, pos, non_zero))
-            .copied()
-            .collect();
-        if co2.len() == 1 {
-            break;
-        }
-    }
-
-    // 4758400 is high
-    println!("Part2: {}", oxy[0] * co2[0]);
-}
-
-const INPUT: &str = r#"011111111101
-011000010111
-000000000101
-000111100001
-011011010000
-001011011100
-111101100010
-010001011011
-010001101001
-101001100011
-111010100010
-110110110110
-110100011110
-001101010010
-110010000000
-110000101111
-111011011010
-010111010010
-011110100010
-000011101001
-001010100111
-001100111100
-101111110110
-101111010101
-110000010111
-000111011000
-110110100001
-010110101010
-100110111011
-010100010111
-010001100001
-110000110111
-111010000101
-100001101110
-111110001100
-001111010100
-100000101011
-100110011110
-111100000001
-001111101100
-000100110010
-001001011010
-010101101110
-110010101010
-001010100010
-101010011011
-001111101111
-100101000001
-010100110011
-011110110000
-001110011010
-011101111111
-111111000000
-101010010101
-101000010101
-011100010110
-111100001101
-011100011001
-010100010001
-010011000000
-000110010001
-101101100100
-011010100101
-010111101100
-111011001101
-000100110011
-101000000111
-010011110011
-000000110000
-000001101000
-010010000111
-010010001101
-110101010110
-000100010110
-101000101010
-000010111111
-110101010001
-101000100010
-001100001111
-101111101011
-110011110000
-101111000000
-111010100001
-100111101101
-000000011010
-100110000111
-000010011100
-100111010001
-111010111101
-110111110100
-010111001001
-010101101100
-111000110000
-000110111111
-011110011011
-101001110000
-000000100101
-000000001000
-000110110001
-001110110011
-111000110111
-101110101010
-101111110011
-101010010111
-101000011011
-011100110100
-000110111110
-010000010110
-010111011111
-110110110100
-110000001100
-011101001001
-101011101111
-011000001110
-111011011110
-100111000110
-001111101101
-000001101101
-001011111000
-001101010110
-011101101100
-001100110011
-100101011011
-111101010011
-100110111100
-111111000010
-100000000011
-110000000100
-011101111011
-111011000000
-111010010101
-010110001101
-110111100100
-101101011101
-011110011111
-000011101010
-001010001101
-110101101110
-111100101101
-100101010101
-001010001011
-111011100010
-010000101111
-101111101111
-101100000100
-101101010001
-010101011111
-001110010111
-010010000100
-111110011011
-011001100111
-110110111100
-110111111110
-001001000010
-000110010100
-001011111011
-111100111100
-011100010111
-010111001110
-111111100101
-001000000110
-111101101100
-000000010100
-110000010100
-011110111010
-111100101011
-111010101110
-010111001101
-011010111110
-000011010111
-011011000100
-110101111100
-000100110111
-000100001001
-111000011000
-000101000101
-100000110100
-110101111011
-011110011001
-111101100000
-010100110111
-101001001101
-100101110001
-111010110111
-010010011010
-011101111000
-101100101111
-010011001000
-011101011000
-100001010101
-001010011011
-001110101010
-000000000001
-110100000010
-111111010110
-100001011110
-011010101011
-101010000000
-101100100110
-010110010101
-001101010011
-011100001001
-000101111110
-000001001011
-111100101110
-010100101010
-001101001111
-010000101100
-110000100111
-110110010111
-011110011000
-110010000001
-101000000010
-101011011101
-010001111101
-111011110111
-101001101001
-100101001110
-101110010100
-001111101000
-101001011011
-111111100011
-110111000011
-100110001110
-111001100001
-100000111000
-011110000101
-000010001000
-011111111011
-001101000000
-101010001001
-010001001001
-001010111100
-011010100110
-011100000001
-001010110101
-110011011010
-001100001010
-000100110001
-111111110111
-111110111011
-101010010001
-010110110001
-001011010100
-100100100101
-000111101000
-111010100000
-100101001111
-010111100001
-001001001011
-111101001110
-011010011111
-100010111000
-111000010111
-100111000100
-010010000000
-010010111101
-001011011110
-110000000000
-001111101010
-010000000111
-111010101100
-000110101010
-010100011000
-101010000011
-101110111100
-110000100010
-000011010011
-000000011101
-101100011001
-000100010111
-110110001001
-101110101101
-110001010100
-001110111011
-010111010101
-111001110101
-100101011111
-011001001100
-110011011111
-100001000000
-110000101000
-100111011111
-110011111011
-101011110011
-111101101010
-100010000010
-110011001001
-110100001010
-111100011001
-100011101010
-101110100010
-110110011000
-101001110011
-000010010011
-101011001001
-010110011010
-111001111110
-101000010010
-010011001101
-010001001100
-110001011000
-000000101101
-001000000101
-001101001011
-001010110000
-100110010010
-110001010110
-010111110110
-010110000101
-000110001011
-011101001100
-001011011011
-000011000110
-010100111110
-010111000110
-101111011110
-110001110111
-001001111110
-010101110000
-011001110101
-101001010101
-111010110000
-110100110110
-001011000001
-001001111101
-110110101100
-010011000001
-110111000001
-011111001000
-100001110101
-000111111110
-111110111010
-000110111000
-100100011000
-010101100111
-101001001011
-001011010111
-100010101000
-000110011001
-101010000010
-001110100110
-000011000010
-010101111100
-101101001110
-111001011001
-110100110101
-111111000101
-100001011000
-100110111000
-101000110001
-001111000100
-110010111101
-011110110011
-111100010100
-110111011001
-011000111110
-111110011100
-101000000100
-000100000010
-100001101010
-110001101010
-100010011000
-100000000111
-011011111011
-001100011101
-101110100110
-011110111011
-001010001100
-111110001010
-110010100101
-010110101100
-001101110110
-001000011010
-100011111100
-101101011100
-001100001101
-101000110000
-000110011010
-111001111010
-011000111100
-000101111100
-001101110001
-101111110010
-101110100001
-110010111111
-000000010011
-000001001101
-010100010010
-100011110001
-101111010000
-101111001101
-011101010101
-000100111111
-110110100110
-010111101001
-001101011111
-010000100010
-110001110000
-110100010101
-001001011000
-101101001100
-011000101001
-111101011100
-100001101111
-101000111000
-110000100001
-000011111101
-101000111100
-101101000001
-010111100110
-101111111001
-011111101000
-100000010111
-100001000010
-011100100000
-110111000111
-011101010001
-111101000110
-110101100011
-000000011011
-010100011100
-010000101001
-010101110001
-001100011111
-010010010000
-011101010110
-011111001101
-011011110011
-000111110111
-110000110001
-101010100101
-100101011010
-000100010011
-100001100010
-000011100101
-110011101011
-010000100100
-010000010100
-101010100001
-101011111000
-000101110101
-010010100011
-100001100011
-100100000111
-010110100101
-100111000001
-101000110101
-110101011011
-000000100111
-000100011011
-111001100110
-001001011100
-011000011010
-010010111000
-010111011001
-011100101100
-000100011101
-010010110000
-011011001001
-010010101111
-001001110101
-101100011000
-100111000010
-011100100011
-000010110001
-111110010100
-100110100110
-101101010111
-111010011010
-101000111110
-010001101110
-001010110110
-101010011010
-001111100000
-101000010000
-100101110101
-100010110100
-111001110010
-111000110001
-000101011011
-101110010000
-011100110000
-100101010110
-111001010111
-000100100110
-000010101001
-101010001011
-000011101000
-000010111011
-111111100110
-001110111111
-111001001010
-000101101000
-110010011010
-101010101011
-011010011100
-010111011010
-000110101000
-011010100000
-111100000101
-010100101100
-001110000010
-111010011111
-100110111001
-011111001010
-001110101110
-010011110010
-001111001101
-000001111000
-011010110101
-001101110111
-110010000010
-100111111101
-000111000011
-000101101101
-010101001000
-111011110000
-000001111110
-001001000011
-100100011001
-000011110001
-001111110111
-001011111111
-011110010001
-010000110000
-111111111010
-101001000001
-000100000111
-110100001100
-111000110010
-000000000110
-111111100000
-001111111000
-110111111101
-000110101111
-111100001111
-100010101001
-000100101011
-100011110101
-111101100110
-001111000010
-010000000011
-101001100010
-100100101101
-000111110000
-010011100000
-111001100100
-100011011110
-110111001111
-010101001111
-011011110110
-100000010010
-001101011010
-001001101100
-001010010000
-001111110101
-110011011110
-001000101100
-010001111010
-010111000000
-100110110101
-110001001001
-010001010011
-100101000100
-011000000000
-100011110100
-011101101010
-010101000011
-001101011100
-001000011011
-011001110010
-000010000000
-000101010000
-001101111110
-011010000101
-110101001010
-010001111000
-111000110100
-001110000000
-010001001110
-111001111100
-010110100010
-001100011000
-101110111111
-010011010100
-100001101101
-011011001101
-111000101011
-100111010110
-101000000101
-111110001110
-010001001111
-001000101101
-000011111110
-100100010011
-011110111000
-010000001110
-111001011110
-001101001010
-010101000001
-011000101111
-101100010010
-000000000000
-010110110111
-101011001010
-101010110101
-001000110001
-100101100101
-101000110100
-110101111111
-010000111111
-111010010110
-101010100111
-001011001111
-010101010101
-110101010011
-111101010111
-001110110010
-000010010010
-011100001100
-010111110100
-011011100010
-101111101110
-111000000100
-111001110100
-110010111110
-111011100000
-001111010111
-101100100001
-110111011110
-111111001101
-100111001101
-111110110100
-101101000111
-110001100101
-110110000010
-101101100011
-110111110001
-011011101100
-011011101110
-100001011100
-001100111001
-100110001100
-011101100101
-101110000001
-001111111110
-101110111010
-111101011011
-100000110000
-110010110100
-001110100010
-011011001000
-100101111011
-010100111100
-101110110000
-001010101010
-001011010010
-111000111100
-111010101111
-010100001100
-001100101111
-001011010001
-101100001110
-010101110010
-110001001010
-000010101010
-111000001000
-100111011011
-110000000101
-011000011011
-000101111111
-010100011011
-001110111101
-100001000111
-111010000011
-001101111011
-011100101111
-010101010111
-111001010011
-011011111100
-110001101100
-010110010011
-101000100000
-010010011100
-101000110010
-101100011010
-101011011001
-111101011110
-001111100111
-101010101000
-001110110111
-000000111110
-101101101010
-010010001110
-010010100001
-110010011111
-010000001001
-011100001110
-111000001111
-010001111111
-111101101111
-010010110110
-100101010111
-110100000111
-110100010000
-101010010010
-100111100100
-000010000001
-101110010010
-101001010100
-101101101011
-010101011000
-110101011010
-111011010110
-110100100001
-011011011010
-010101001101
-110101000011
-111010110101
-110100111001
-101011000110
-010101110110
-010110100000
-101101101100
-111101010100
-010110010010
-011000111101
-011100100100
-000000001111
-011101011110
-011111001100
-111110100101
-001100001001
-000101100101
-101101100111
-010101010001
-000111001000
-011001110011
-101101111110
-110110111101
-000010011110
-001000110111
-110001101000
-111101010001
-111001110011
-100111101110
-011100110011
-011110001100
-100001100000
-100011100000
-011100000111
-001101000101
-111001010101
-001001010000
-000010101011
-011001101000
-100000000000
-100101011001
-100000100110
-100000111100
-001110110001
-111111111111
-100111110011
-110000011000
-111010000110
-000010001001
-101111100001
-110100100110
-011001000001
-101011100101
-001110100100
-000110011111
-010110011000
-110000100011
-101101111001
-100101101010
-111110110001
-100101000000
-010001110111
-011001010011
-011010001011
-001010101000
-011100001000
-001001110011
-001110000001
-010101011010
-000100100101
-100110110110
-001011111101
-011101001110
-100100111110
-110100111101
-010100111001
-011010001101
-000111100101
-010011001111
-010011001011
-111101010110
-000100011001
-100110101111
-111000111101
-110101000101
-010110001001
-001001100001
-101010101010
-011011101011
-000001101111
-111000011101
-101001010011
-011101001101
-111011111100
-010001010100
-110000101010
-111010000100
-011001100110
-011011011110
-010111001100
-111110010111
-100100001000
-110011110010
-100101110110
-101011111100
-000010110100
-001010110001
-011111111100
-010101100010
-001101010101
-000101001000
-000101101100
-110110100101
-010111001111
-100100001001
-111000100110
-011000110010
-111100011111
-100100110110
-111100100110
-111111010111
-000111100110
-111100010000
-110101100010
-001111100101
-010001001000
-000000001110
-101001000110
-011100000101
-011101110101
-010100101101
-110110101011
-001011110010
-001010011100
-100010111010
-011011111111
-011101101101
-100010110010
-111111001111
-100110010100
-010011000011
-100101111110
-101100101000
-011010011011
-100001000100
-001000111101
-101001111000
-100001110011
-100111101001
-101110001010
-010110011001
-110010101111
-011101000001
-110000100100
-100000111001
-101010011000
-101011000010
-001100010110
-011010111000
-110010010000
-100101110000
-001111110010
-110011010100
-011110101101
-010101011011
-110111011111
-000010100010
-000101111010
-010010010111
-011010111101
-010101111010
-111001000100
-100001100101
-111010001010
-110001111011
-111100011110
-100011111011
-011111110111
-010000101101
-010100001001
-001000110000
-001011010110
-010001100110
-101000001000
-101100110110
-010011101000
-000111111001
-110101011101
-010000100110
-100011011000
-110110000011
-001001001010
-001000101010
-000001111101
-111111011000
-111000111011
-110011110101
-101111111100
-110010111011
-001011010011
-001001011001
-100101111111
-010100110101
-100100101011
-011111100110
-001011110111
-111110101111
-000101100100
-111101001100
-110011101000
-101110001000
-010011100001
-110000111101
-011010010110
-000011101100
-011111100000
-110100000011
-011110111101
-010000011110
-100000011011
-001111011111
-000111011111
-111001111101
-011101010000
-011010101100
-110100100100
-000011011100
-101011110110
-011100111001
-100101010010
-111011110101
-110011000000
-101001100111
-101110011110
-000101000000
-110101111010
-111000010110
-100010110110
-100110000110
-010000100011
-100001111011
-110111000110
-010110101101
-111100111000
-010110000100
-011110010011
-001011011000
-101000101000
-100111110000
-111101110101
-101110011000
-011001110110
-111110010011
-100001011010
-011110111111
-011101110111
-100111110101
-101110111110
-010111000111
-011001111011
-001110101100
-011011101101
-000111000001
-101001111001
-110001010001
-011001001011
-001101011000
-001010010010
-011100111101
-110111001100
-010011011010
-001001100100
-100101111101
-100110001010
-110101011001
-110011101010
-001111111100
-100011000000
-000111001101
-010010010101
-010110000000
-100111111100
-"#;
@@ -0,0 +1,141 @@
+use anyhow::{anyhow, Result};
+use aoc::parse::{self, complete, int, preceded, separated1, space0, space1};
+use aoc::{LineError, Solution};
+
+#[derive(Debug)]
+struct BingoBoard {
+    grid: Vec<Vec<u32>>,
+    marks: Vec<(usize, usize)>,
+    has_won: bool,
+}
+
+impl BingoBoard {
+    fn new(board: Vec<Vec<u32>>) -> BingoBoard {
+        BingoBoard {
+            grid: board,
+            marks: Vec::new(),
+            has_won: false,
+        }
+    }
+
+    fn mark_num(&mut self, n: u32) -> Option<(usize, usize)> {
+        let maybe_pos = self.grid.iter().enumerate().find_map(|(i, row)| {
+            row.iter()
+                .enumerate()
+                .find_map(|(j, &x)| if x == n { Some((i, j)) } else { None })
+        });
+        if let Some(pos) = maybe_pos {
+            self.marks.push(pos);
+        }
+        maybe_pos
+    }
+
+    fn check_win(&self) -> Option<Vec<u32>> {
+        let height = self.grid.len();
+        let width = self.grid[0].len();
+
+        let rows = self
+            .marks
+            .iter()
+            .fold(vec![Vec::new(); height], |mut v, &pos| {
+                v[pos.0].push(pos);
+                v
+            });
+        let row_win = rows.iter().find(|r| r.len() == width);
+        if let Some(row) = row_win {
+            Some(row.iter().map(|&(i, j)| self.grid[i][j]).collect())
+        } else {
+            let cols = self
+                .marks
+                .iter()
+                .fold(vec![Vec::new(); width], |mut v, &pos| {
+                    v[pos.1].push(pos);
+                    v
+                });
+            let col_win = cols.iter().find(|r| r.len() == height);
+            col_win.map(|col| col.iter().map(|&(i, j)| self.grid[i][j]).collect())
+        }
+    }
+
+    fn set_win(&mut self) {
+        self.has_won = true
+    }
+
+    fn sum(&self) -> u32 {
+        self.grid.iter().map(|row| row.iter().sum::<u32>()).sum()
+    }
+
+    fn sum_marked(&self) -> u32 {
+        self.marks.iter().map(|&(i, j)| self.grid[i][j]).sum()
+    }
+}
+
+#[derive(Debug)]
+pub struct Bingo {
+    nums: Vec<u32>,
+    grids: Vec<Vec<Vec<u32>>>,
+}
+
+impl Bingo {
+    /// Plays every board to completion, returning the winning scores in the order the boards won.
+    fn scores(&self) -> Vec<u32> {
+        let mut boards: Vec<BingoBoard> = self.grids.iter().cloned().map(BingoBoard::new).collect();
+        let mut scores = Vec::with_capacity(boards.len());
+
+        for &num in &self.nums {
+            for board in boards.iter_mut() {
+                if !board.has_won {
+                    board.mark_num(num);
+                    if board.check_win().is_some() {
+                        let unmarked_sum: u32 = board.sum() - board.sum_marked();
+                        scores.push(num * unmarked_sum);
+                        board.set_win();
+                    }
+                }
+            }
+        }
+        scores
+    }
+}
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    const DAY: u32 = 4;
+    type Input = Bingo;
+    type Part1 = u32;
+    type Part2 = u32;
+
+    fn parse(input: &str) -> Result<Bingo> {
+        let mut sections = parse::sections(input);
+        let (line, nums) = sections
+            .next()
+            .ok_or_else(|| anyhow!("expected the numbers drawn"))?;
+        let nums = parse::int_list(nums).map_err(|error| LineError { line, error })?;
+
+        let row = || preceded(space0(), separated1(int(), space1()));
+        let grids = sections
+            .map(|(first, board)| {
+                board
+                    .lines()
+                    .enumerate()
+                    .map(|(i, l)| {
+                        complete(row(), l.trim_end()).map_err(|error| LineError {
+                            line: first + i,
+                            error,
+                        })
+                    })
+                    .collect()
+            })
+            .collect::<Result<_, _>>()?;
+        Ok(Bingo { nums, grids })
+    }
+
+    fn part1(bingo: &Bingo) -> u32 {
+        bingo.scores()[0]
+    }
+
+    fn part2(bingo: &Bingo) -> u32 {
+        *bingo.scores().last().unwrap()
+    }
+}
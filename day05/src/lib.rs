@@ -0,0 +1,104 @@
+use anyhow::Result;
+use aoc::parse::{complete, int, map, pair, tag, terminated, ParseError, Parser};
+use aoc::Solution;
+use itertools::Itertools;
+use std::collections::HashSet;
+use std::str::FromStr;
+use vectory::{IntVector, Segment};
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct Vent {
+    line: Segment<2>,
+}
+
+impl Vent {
+    fn is_ortho(&self) -> bool {
+        let dir = self.line.direction();
+        dir[0] == 0 || dir[1] == 0
+    }
+}
+
+/// A point, like `0,9`.
+fn point<'a>() -> impl Parser<'a, IntVector<2>> {
+    map(pair(terminated(int(), tag(",")), int()), |(x, y)| {
+        IntVector::from([x, y])
+    })
+}
+
+/// A vent, like `0,9 -> 5,9`.
+fn vent<'a>() -> impl Parser<'a, Vent> {
+    map(
+        pair(terminated(point(), tag(" -> ")), point()),
+        |(start, end)| Vent {
+            line: Segment::new(start, end),
+        },
+    )
+}
+
+impl FromStr for Vent {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        complete(vent(), s)
+    }
+}
+
+/// Counts the lattice points covered by at least two vents, from the pairwise intersections
+/// rather than by walking every vent.
+fn overlap_count<'a>(vents: impl Iterator<Item = &'a Vent> + Clone) -> usize {
+    let overlaps: HashSet<IntVector<2>> = vents
+        .tuple_combinations()
+        .filter_map(|(a, b)| a.line.intersection(&b.line))
+        .flat_map(|x| x.lattice_points())
+        .collect();
+    overlaps.len()
+}
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    const DAY: u32 = 5;
+    type Input = Vec<Vent>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Vec<Vent>> {
+        Ok(aoc::parse_lines(input)?)
+    }
+
+    fn part1(input: &Vec<Vent>) -> usize {
+        overlap_count(input.iter().filter(|v| v.is_ortho()))
+    }
+
+    fn part2(input: &Vec<Vent>) -> usize {
+        overlap_count(input.iter())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::*;
+
+    #[test]
+    fn parse_errors() {
+        assert_eq!(
+            Ok(Vent {
+                line: Segment::new([0, 9].into(), [5, 9].into())
+            }),
+            "0,9 -> 5,9".parse()
+        );
+        let err = Day05::parse("0,9 -> 5,9\n0,9 => 5,9").unwrap_err();
+        assert_eq!(
+            "line 2, column 4: expected \" -> \", found ' '",
+            err.to_string()
+        );
+        assert_eq!(
+            "column 11: expected the end of the line, found \",1\"",
+            "0,9 -> 5,9,1".parse::<Vent>().unwrap_err().to_string()
+        );
+        assert_eq!(
+            "column 8: expected an integer, found the end of the line",
+            "0,9 -> ".parse::<Vent>().unwrap_err().to_string()
+        );
+    }
+}
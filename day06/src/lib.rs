@@ -0,0 +1,65 @@
+use anyhow::Result;
+use aoc::Solution;
+use sim::{Driver, Simulation};
+use vectory::{IntVector, Matrix};
+
+/// Every lanternfish, by the days left until it next spawns.
+struct School(Vec<u32>);
+
+/// A step is a day, reporting how many fish were born.
+impl Simulation for School {
+    type Outcome = usize;
+
+    fn step(&mut self) -> usize {
+        let mut new_fish = Vec::new();
+        for f in self.0.iter_mut() {
+            if *f == 0 {
+                *f = 6;
+                new_fish.push(8);
+            } else {
+                *f -= 1;
+            }
+        }
+        let born = new_fish.len();
+        self.0.append(&mut new_fish);
+        born
+    }
+}
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    const DAY: u32 = 6;
+    type Input = Vec<u32>;
+    type Part1 = usize;
+    type Part2 = u64;
+
+    fn parse(input: &str) -> Result<Vec<u32>> {
+        Ok(aoc::parse::int_list(input.trim())?)
+    }
+
+    fn part1(fish: &Vec<u32>) -> usize {
+        let mut school = Driver::new(School(fish.clone()));
+        school.run(80);
+        school.sim().0.len()
+    }
+
+    fn part2(fish: &Vec<u32>) -> u64 {
+        let mut ages = [0u64; 9];
+        for &f in fish {
+            ages[f as usize] += 1;
+        }
+
+        // One day as a linear map on the age counts: everyone ages by a day, and each fish at age 0
+        // resets to 6 and spawns a new fish at 8.
+        let mut day = Matrix { xs: [[0u64; 9]; 9] };
+        for age in 0..8 {
+            day.xs[age][age + 1] = 1;
+        }
+        day.xs[6][0] = 1;
+        day.xs[8][0] = 1;
+
+        let counts = &day.pow(256) * &IntVector::from(ages);
+        counts.iter().sum()
+    }
+}
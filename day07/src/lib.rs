@@ -0,0 +1,45 @@
+use anyhow::Result;
+use aoc::Solution;
+use std::ops::RangeInclusive;
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    const DAY: u32 = 7;
+    type Input = Vec<i32>;
+    type Part1 = i32;
+    type Part2 = i32;
+
+    fn parse(input: &str) -> Result<Vec<i32>> {
+        Ok(aoc::parse::int_list(input.trim())?)
+    }
+
+    fn part1(input: &Vec<i32>) -> i32 {
+        positions(input)
+            .map(|p| input.iter().map(|x| (x - p).abs()).sum::<i32>())
+            .min()
+            .unwrap()
+    }
+
+    fn part2(input: &Vec<i32>) -> i32 {
+        positions(input)
+            .map(|p| {
+                input
+                    .iter()
+                    .map(|x| {
+                        let n = (x - p).abs();
+                        n * (n + 1) / 2
+                    })
+                    .sum::<i32>()
+            })
+            .min()
+            .unwrap()
+    }
+}
+
+/// Every candidate position for the crabs to line up on.
+fn positions(input: &[i32]) -> RangeInclusive<i32> {
+    let min = input.iter().copied().min().unwrap_or(0);
+    let max = input.iter().copied().max().unwrap_or(0);
+    min..=max
+}
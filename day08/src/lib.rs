@@ -0,0 +1,233 @@
+use anyhow::Result;
+use aoc::Solution;
+use std::collections::{HashMap, HashSet};
+use std::str::FromStr;
+use thiserror::Error;
+
+#[derive(Debug, Eq, PartialEq)]
+pub struct Record {
+    alphabet: HashSet<String>,
+    message: Vec<String>,
+}
+
+fn sort_str(s: &str) -> String {
+    let mut chs: Vec<char> = s.chars().collect();
+    chs.sort();
+    chs.iter().collect()
+}
+
+/// Why a line isn't a record of a display. Columns count from 1.
+#[derive(Clone, Debug, Eq, PartialEq, Error)]
+pub enum ParseRecordError {
+    #[error("column {column}: expected the patterns and the output separated by \" | \"")]
+    Separator { column: usize },
+    #[error("column {column}: expected a pattern of segments a to g, found {token:?}")]
+    Pattern { column: usize, token: String },
+    #[error("column {column}: expected {expected} different patterns, found {found}")]
+    Count {
+        column: usize,
+        expected: usize,
+        found: usize,
+    },
+    #[error("column {column}: output {token:?} isn't one of the patterns")]
+    Output { column: usize, token: String },
+}
+
+/// The words of `s`, each checked to be a pattern of segments and sorted, with their columns
+/// counted from `column`.
+fn patterns(s: &str, column: usize) -> Result<Vec<(usize, String)>, ParseRecordError> {
+    aoc::words(s)
+        .map(|(c, token)| {
+            let column = column + c - 1;
+            let sorted = sort_str(token);
+            let segments = sorted.chars().collect::<Vec<_>>();
+            if segments.iter().all(|ch| ('a'..='g').contains(ch))
+                && segments.windows(2).all(|w| w[0] != w[1])
+            {
+                Ok((column, sorted))
+            } else {
+                Err(ParseRecordError::Pattern {
+                    column,
+                    token: token.to_string(),
+                })
+            }
+        })
+        .collect()
+}
+
+impl FromStr for Record {
+    type Err = ParseRecordError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (alpha, mess) = s.split_once(" | ").ok_or(ParseRecordError::Separator {
+            column: aoc::end_column(s),
+        })?;
+        let separator = alpha.chars().count() + 1;
+
+        let alphabet: HashSet<String> = patterns(alpha, 1)?.into_iter().map(|(_, p)| p).collect();
+        if alphabet.len() != 10 {
+            return Err(ParseRecordError::Count {
+                column: separator,
+                expected: 10,
+                found: alphabet.len(),
+            });
+        }
+        let message = patterns(mess, separator + 3)?;
+        if message.len() != 4 {
+            return Err(ParseRecordError::Count {
+                column: aoc::end_column(s),
+                expected: 4,
+                found: message.len(),
+            });
+        }
+        if let Some((column, token)) = message.iter().find(|(_, p)| !alphabet.contains(p)) {
+            return Err(ParseRecordError::Output {
+                column: *column,
+                token: token.clone(),
+            });
+        }
+        let message = message.into_iter().map(|(_, p)| p).collect();
+        Ok(Record { alphabet, message })
+    }
+}
+
+fn crack(alphabet: &HashSet<String>) -> HashMap<String, char> {
+    let mut one_set: HashSet<char> = HashSet::new();
+    let mut four_set: HashSet<char> = HashSet::new();
+
+    let mut ret: HashMap<String, char> = alphabet
+        .iter()
+        .filter(|s| [2, 3, 4, 7].contains(&s.len()))
+        .map(|s| match s.len() {
+            2 => {
+                one_set.extend(s.chars());
+                (s.clone(), '1')
+            }
+            3 => (s.clone(), '7'),
+            4 => {
+                four_set.extend(s.chars());
+                (s.clone(), '4')
+            }
+            7 => (s.clone(), '8'),
+            _ => panic!("Filter error"),
+        })
+        .collect();
+
+    ret.extend(
+        alphabet
+            .iter()
+            .filter(|s| [5, 6].contains(&s.len()))
+            .map(|s| match s.len() {
+                5 => {
+                    let chs = HashSet::from_iter(s.chars());
+                    if chs.is_superset(&one_set) {
+                        (s.clone(), '3')
+                    } else if four_set.difference(&chs).count() == 1 {
+                        (s.clone(), '5')
+                    } else {
+                        (s.clone(), '2')
+                    }
+                }
+                6 => {
+                    let chs = HashSet::from_iter(s.chars());
+                    if !chs.is_superset(&one_set) {
+                        (s.clone(), '6')
+                    } else if chs.is_superset(&four_set) {
+                        (s.clone(), '9')
+                    } else {
+                        (s.clone(), '0')
+                    }
+                }
+                _ => panic!("Filter error"),
+            }),
+    );
+    assert_eq!(10, ret.len(), "Alphabet: {:?}, dict: {:?}", &alphabet, &ret);
+
+    ret
+}
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    const DAY: u32 = 8;
+    type Input = Vec<Record>;
+    type Part1 = usize;
+    type Part2 = u32;
+
+    fn parse(input: &str) -> Result<Vec<Record>> {
+        Ok(aoc::parse_lines(input)?)
+    }
+
+    fn part1(input: &Vec<Record>) -> usize {
+        input
+            .iter()
+            .map(|r| {
+                r.message
+                    .iter()
+                    .filter(|digit| [2, 4, 3, 7].contains(&digit.len()))
+                    .count()
+            })
+            .sum()
+    }
+
+    fn part2(input: &Vec<Record>) -> u32 {
+        input
+            .iter()
+            .map(|r| {
+                let dict: HashMap<String, char> = crack(&r.alphabet);
+                r.message
+                    .iter()
+                    .map(|s| dict[s])
+                    .collect::<String>()
+                    .parse::<u32>()
+                    .unwrap()
+            })
+            .sum()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::*;
+
+    const PATTERNS: &str = "acedgfb cdfbe gcdfa fbcad dab cefabd cdfgeb eafb cagedb ab";
+
+    #[test]
+    fn parse_errors() {
+        let record: Record = format!("{} | cdfeb fcadb cdfeb cdbaf", PATTERNS)
+            .parse()
+            .unwrap();
+        assert_eq!(vec!["bcdef", "abcdf", "bcdef", "abcdf"], record.message);
+        assert_eq!(
+            Err(ParseRecordError::Separator { column: 59 }),
+            PATTERNS.parse::<Record>()
+        );
+        assert_eq!(
+            Err(ParseRecordError::Pattern {
+                column: 65,
+                token: "xy".to_string()
+            }),
+            format!("{} | ab xy ab ab", PATTERNS).parse::<Record>()
+        );
+        assert_eq!(
+            Err(ParseRecordError::Count {
+                column: 6,
+                expected: 10,
+                found: 1
+            }),
+            "ab ba | ab ab ab ab".parse::<Record>()
+        );
+        assert_eq!(
+            Err(ParseRecordError::Output {
+                column: 65,
+                token: "abc".to_string()
+            }),
+            format!("{} | ab cba ab ab", PATTERNS).parse::<Record>()
+        );
+        let err = Day08::parse(&format!("{} | ab\n", PATTERNS)).unwrap_err();
+        assert_eq!(
+            "line 1, column 64: expected 4 different patterns, found 1",
+            err.to_string()
+        );
+    }
+}
@@ -0,0 +1,73 @@
+use anyhow::Result;
+use aoc::Solution;
+use grid::Grid;
+use std::collections::HashSet;
+use std::str::FromStr;
+
+#[derive(Debug)]
+pub struct HeightField {
+    grid: Grid<u32>,
+}
+
+impl FromStr for HeightField {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(HeightField { grid: s.parse()? })
+    }
+}
+
+impl HeightField {
+    pub fn low_points(&self) -> impl Iterator<Item = (usize, usize)> + '_ {
+        self.grid.positions().filter(move |&p| {
+            self.grid
+                .neighbours4(p)
+                .all(|n| self.grid[p] < self.grid[n])
+        })
+    }
+
+    pub fn basin(&self, p: (usize, usize)) -> HashSet<(usize, usize)> {
+        let mut ret = HashSet::new();
+
+        self.basin_internal(p, &mut ret);
+
+        ret
+    }
+
+    fn basin_internal(&self, p: (usize, usize), basin_set: &mut HashSet<(usize, usize)>) {
+        if self.grid[p] < 9 {
+            basin_set.insert(p);
+
+            for n in self.grid.neighbours4(p) {
+                if !basin_set.contains(&n) {
+                    self.basin_internal(n, basin_set);
+                }
+            }
+        }
+    }
+}
+
+pub struct Day09;
+
+impl Solution for Day09 {
+    const DAY: u32 = 9;
+    type Input = HeightField;
+    type Part1 = u32;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<HeightField> {
+        input.parse()
+    }
+
+    fn part1(input: &HeightField) -> u32 {
+        input.low_points().map(|p| input.grid[p] + 1).sum()
+    }
+
+    fn part2(input: &HeightField) -> usize {
+        let mut basins: Vec<usize> = input.low_points().map(|p| input.basin(p).len()).collect();
+        basins.sort();
+        basins.reverse();
+
+        basins[..3].iter().product()
+    }
+}
@@ -0,0 +1,157 @@
+use anyhow::Result;
+use aoc::{LineError, Solution};
+use thiserror::Error;
+
+/// A character that isn't a bracket. Columns count from 1.
+#[derive(Clone, Debug, Eq, PartialEq, Error)]
+#[error("column {column}: expected one of ()[]{{}}<>, found {token:?}")]
+pub struct ParseChunkError {
+    pub column: usize,
+    pub token: char,
+}
+
+fn parse_line(s: &str) -> Result<String, ParseChunkError> {
+    let bad = s
+        .chars()
+        .enumerate()
+        .find(|&(_, ch)| !"()[]{}<>".contains(ch));
+    match bad {
+        Some((i, token)) => Err(ParseChunkError {
+            column: i + 1,
+            token,
+        }),
+        None => Ok(s.to_string()),
+    }
+}
+
+struct Checker {
+    stack: Vec<char>,
+}
+
+impl Checker {
+    fn new() -> Checker {
+        Checker { stack: Vec::new() }
+    }
+
+    /// Follows the chunks of `s`, failing at the first closing bracket that doesn't close the
+    /// chunk that's open, including one with nothing open.
+    fn check(&mut self, s: &str) -> Result<(), (char, usize)> {
+        for (pos, ch) in s.chars().enumerate() {
+            match ch {
+                '(' | '[' | '{' | '<' => self.stack.push(ch),
+                _ => {
+                    if self.stack.last().map(|&open| Checker::closer(open)) == Some(ch) {
+                        self.stack.pop();
+                    } else {
+                        return Err((ch, pos));
+                    }
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// The brackets that close what `s` leaves open, or `None` if it's corrupted.
+    fn complete(&mut self, s: &str) -> Option<Vec<char>> {
+        self.check(s).ok()?;
+        Some(
+            self.stack
+                .iter()
+                .rev()
+                .map(|&ch| Checker::closer(ch))
+                .collect(),
+        )
+    }
+
+    fn closer(ch: char) -> char {
+        match ch {
+            '(' => ')',
+            '{' => '}',
+            '[' => ']',
+            '<' => '>',
+            _ => panic!("Bad stack state {}", ch),
+        }
+    }
+}
+
+fn score(ch: char) -> u64 {
+    match ch {
+        ')' => 3,
+        ']' => 57,
+        '}' => 1197,
+        '>' => 25137,
+        _ => panic!("Can't score {}", ch),
+    }
+}
+
+fn complete_score(ch: char) -> u64 {
+    match ch {
+        ')' => 1,
+        ']' => 2,
+        '}' => 3,
+        '>' => 4,
+        _ => panic!("Can't score {}", ch),
+    }
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    const DAY: u32 = 10;
+    type Input = Vec<String>;
+    type Part1 = u64;
+    type Part2 = u64;
+
+    fn parse(input: &str) -> Result<Vec<String>> {
+        Ok(input
+            .lines()
+            .enumerate()
+            .map(|(i, l)| parse_line(l).map_err(|error| LineError { line: i + 1, error }))
+            .collect::<Result<_, _>>()?)
+    }
+
+    fn part1(input: &Vec<String>) -> u64 {
+        input
+            .iter()
+            .filter_map(|line| Checker::new().check(line).err())
+            .map(|(ch, _pos)| score(ch))
+            .sum()
+    }
+
+    fn part2(input: &Vec<String>) -> u64 {
+        let mut scores = Vec::new();
+        for completion in input.iter().filter_map(|l| Checker::new().complete(l)) {
+            let mut complete_score_total = 0;
+            for ch in completion {
+                complete_score_total = complete_score_total * 5 + complete_score(ch);
+            }
+            scores.push(complete_score_total);
+        }
+        scores.sort();
+
+        scores[scores.len() / 2]
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::*;
+
+    #[test]
+    fn parse_errors() {
+        let err = Day10::parse("[]\n(<x>)\n").unwrap_err();
+        assert_eq!(
+            "line 2, column 3: expected one of ()[]{}<>, found 'x'",
+            err.to_string()
+        );
+    }
+
+    #[test]
+    fn closing_with_nothing_open() {
+        assert_eq!(Err((']', 0)), Checker::new().check("]("));
+        assert_eq!(None, Checker::new().complete("()>"));
+        assert_eq!(Some(vec![']', ')']), Checker::new().complete("([<>"));
+        assert_eq!(57, Day10::part1(&Day10::parse("]\n").unwrap()));
+    }
+}
@@ -0,0 +1,106 @@
+use aoc::Solution;
+use grid::Grid;
+use render::{heat, Picture, Rgb};
+use sim::{Driver, Simulation};
+use std::collections::HashSet;
+
+#[derive(Debug)]
+pub struct Board {
+    grid: Grid<u32>,
+}
+
+impl Board {
+    /// The octopuses' energy levels as a heatmap, with those that just flashed in white.
+    fn picture(&self) -> Picture {
+        Picture::from_grid(&self.grid, |&energy| {
+            if energy == 0 {
+                Rgb::WHITE
+            } else {
+                heat(energy as f64 / 9.0)
+            }
+        })
+    }
+
+    fn sum(&self) -> usize {
+        self.grid.iter().map(|x| *x as usize).sum()
+    }
+
+    fn increase(&mut self) {
+        self.grid.iter_mut().for_each(|cell| *cell += 1)
+    }
+
+    fn flash(&mut self, pos: (usize, usize), flashed: &mut HashSet<(usize, usize)>) {
+        flashed.insert(pos);
+
+        let neighbors: Vec<_> = self.grid.neighbours8(pos).collect();
+        for neighbor in neighbors {
+            self.grid[neighbor] += 1;
+            if self.grid[neighbor] > 9 && !flashed.contains(&neighbor) {
+                self.flash(neighbor, flashed);
+            }
+        }
+    }
+}
+
+/// A step reports how many octopuses flashed.
+impl Simulation for Board {
+    type Outcome = usize;
+
+    fn step(&mut self) -> usize {
+        self.increase();
+
+        let mut flashed = HashSet::new();
+        for pos in self.grid.positions() {
+            if self.grid[pos] > 9 && !flashed.contains(&pos) {
+                self.flash(pos, &mut flashed);
+            }
+        }
+
+        let count = flashed.len();
+        for pos in flashed {
+            self.grid[pos] = 0;
+        }
+        count
+    }
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    const DAY: u32 = 11;
+    type Input = Grid<u32>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> anyhow::Result<Grid<u32>> {
+        Ok(input.parse()?)
+    }
+
+    fn part1(input: &Grid<u32>) -> usize {
+        let board = Board {
+            grid: input.clone(),
+        };
+
+        Driver::new(board).run(100).into_iter().sum()
+    }
+
+    fn part2(input: &Grid<u32>) -> usize {
+        let board = Board {
+            grid: input.clone(),
+        };
+
+        Driver::new(board).until(|board, _| board.sum() == 0)
+    }
+}
+
+/// The octopuses from the start until they all flash at once, a frame a step.
+pub fn draw(input: &Grid<u32>) -> Vec<Picture> {
+    let board = Board {
+        grid: input.clone(),
+    };
+    let mut frames = vec![board.picture()];
+    Driver::new(board)
+        .observe(|_, board, _| frames.push(board.picture()))
+        .until(|board, _| board.sum() == 0);
+    frames
+}
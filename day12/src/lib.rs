@@ -0,0 +1,143 @@
+use aoc::Solution;
+use std::str::FromStr;
+
+use anyhow::Result;
+use thiserror::Error;
+
+#[derive(Debug)]
+pub struct Edge {
+    left: String,
+    right: String,
+}
+
+impl Edge {
+    fn map(&self, node: &str) -> Option<&str> {
+        if self.left == node {
+            Some(&self.right)
+        } else if self.right == node {
+            Some(&self.left)
+        } else {
+            None
+        }
+    }
+}
+
+/// Why a line isn't an edge between caves. Columns count from 1.
+#[derive(Clone, Debug, Eq, PartialEq, Error)]
+pub enum ParseEdgeError {
+    #[error("column {column}: expected two caves joined by \"-\"")]
+    Missing { column: usize },
+    #[error("column {column}: expected the name of a cave, found {token:?}")]
+    Cave { column: usize, token: String },
+}
+
+impl FromStr for Edge {
+    type Err = ParseEdgeError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (left, right) = s.split_once('-').ok_or(ParseEdgeError::Missing {
+            column: aoc::end_column(s),
+        })?;
+        let cave = |column: usize, token: &str| {
+            if !token.is_empty() && token.chars().all(|ch| ch.is_ascii_alphabetic()) {
+                Ok(token.to_string())
+            } else {
+                Err(ParseEdgeError::Cave {
+                    column,
+                    token: token.to_string(),
+                })
+            }
+        };
+        Ok(Edge {
+            left: cave(1, left)?,
+            right: cave(left.chars().count() + 2, right)?,
+        })
+    }
+}
+
+fn is_lower(s: &str) -> bool {
+    s.chars().all(|ch| ch.is_lowercase())
+}
+
+fn paths2<'a>(
+    edges: &'a [Edge],
+    start: &'a str,
+    end: &str,
+    mut visited: Vec<&'a str>,
+    revisited: bool,
+) -> Vec<Vec<&'a str>> {
+    visited.push(start);
+
+    if start == end {
+        return vec![visited];
+    }
+
+    let sub_paths: Vec<Vec<&str>> = edges
+        .iter()
+        .filter_map(|e| e.map(start))
+        .flat_map(|n| {
+            if !is_lower(n) || !visited.contains(&n) {
+                // Upper or new
+                let mut v = Vec::with_capacity(visited.len() + 1);
+                v.extend_from_slice(&visited);
+                paths2(edges, n, end, v, revisited)
+            } else if !revisited && n != "start" && n != "end" {
+                let mut v = Vec::with_capacity(visited.len() + 1);
+                v.extend_from_slice(&visited);
+                paths2(edges, n, end, v, true)
+            } else {
+                Vec::new()
+            }
+        })
+        .collect();
+
+    sub_paths
+}
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    const DAY: u32 = 12;
+    type Input = Vec<Edge>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Vec<Edge>> {
+        Ok(aoc::parse_lines(input)?)
+    }
+
+    /// Part 1 forbids the one revisit of a small cave that part 2 allows, so it's part 2's search
+    /// with the revisit already spent.
+    fn part1(input: &Vec<Edge>) -> usize {
+        paths2(input, "start", "end", Vec::new(), true).len()
+    }
+
+    fn part2(input: &Vec<Edge>) -> usize {
+        paths2(input, "start", "end", Vec::new(), false).len()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::*;
+
+    #[test]
+    fn parse_errors() {
+        assert_eq!(
+            Err(ParseEdgeError::Missing { column: 6 }),
+            "start".parse::<Edge>().map(|_| ())
+        );
+        assert_eq!(
+            Err(ParseEdgeError::Cave {
+                column: 4,
+                token: "b-c".to_string()
+            }),
+            "HN-b-c".parse::<Edge>().map(|_| ())
+        );
+        let err = Day12::parse("start-A\n-end\n").unwrap_err();
+        assert_eq!(
+            "line 2, column 1: expected the name of a cave, found \"\"",
+            err.to_string()
+        );
+    }
+}
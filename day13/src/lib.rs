@@ -0,0 +1,233 @@
+use anyhow::Result;
+use aoc::{LineError, Solution};
+use render::{Picture, Rgb};
+use std::collections::HashSet;
+use std::str::FromStr;
+use thiserror::Error;
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum Fold {
+    X(i32),
+    Y(i32),
+}
+
+/// Why a line isn't a fold. Columns count from 1.
+#[derive(Clone, Debug, Eq, PartialEq, Error)]
+pub enum ParseFoldError {
+    #[error("column {column}: expected \"fold along\", found {token:?}")]
+    Instruction { column: usize, token: String },
+    #[error("column {column}: expected x=<n> or y=<n>, found {token:?}")]
+    Axis { column: usize, token: String },
+    #[error("column {column}: fold position is not a number: {token:?}")]
+    Position { column: usize, token: String },
+    #[error("column {column}: expected \"fold along\" and a position")]
+    Missing { column: usize },
+    #[error("column {column}: unexpected {token:?} after the position")]
+    Trailing { column: usize, token: String },
+}
+
+impl FromStr for Fold {
+    type Err = ParseFoldError;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        let missing = ParseFoldError::Missing {
+            column: aoc::end_column(s),
+        };
+        let mut words = aoc::words(s);
+        for expected in ["fold", "along"] {
+            match words.next().ok_or(missing.clone())? {
+                (_, w) if w == expected => {}
+                (column, token) => {
+                    return Err(ParseFoldError::Instruction {
+                        column,
+                        token: token.to_string(),
+                    })
+                }
+            }
+        }
+        let (column, word) = words.next().ok_or(missing)?;
+        if let Some((column, token)) = words.next() {
+            return Err(ParseFoldError::Trailing {
+                column,
+                token: token.to_string(),
+            });
+        }
+
+        let axis_error = || ParseFoldError::Axis {
+            column,
+            token: word.to_string(),
+        };
+        let (axis, at) = word.split_once('=').ok_or_else(axis_error)?;
+        let at = at.parse().map_err(|_| ParseFoldError::Position {
+            column: column + axis.chars().count() + 1,
+            token: at.to_string(),
+        })?;
+        match axis {
+            "x" => Ok(Fold::X(at)),
+            "y" => Ok(Fold::Y(at)),
+            _ => Err(axis_error()),
+        }
+    }
+}
+
+/// Why a line isn't a dot. Columns count from 1.
+#[derive(Clone, Debug, Eq, PartialEq, Error)]
+pub enum ParseDotError {
+    #[error("column {column}: expected a dot as x,y")]
+    Missing { column: usize },
+    #[error("column {column}: coordinate is not a number: {token:?}")]
+    Coordinate { column: usize, token: String },
+}
+
+fn parse_dot(s: &str) -> std::result::Result<(i32, i32), ParseDotError> {
+    let (x, y) = s.split_once(',').ok_or(ParseDotError::Missing {
+        column: aoc::end_column(s),
+    })?;
+    let coordinate = |column: usize, token: &str| {
+        token.parse().map_err(|_| ParseDotError::Coordinate {
+            column,
+            token: token.to_string(),
+        })
+    };
+    Ok((coordinate(1, x)?, coordinate(x.chars().count() + 2, y)?))
+}
+
+impl Fold {
+    fn fold_dim(n: i32, at: i32) -> i32 {
+        if n < at {
+            n
+        } else {
+            let past = n - at;
+            at - past
+        }
+    }
+
+    fn exec(&self, (x, y): (i32, i32)) -> (i32, i32) {
+        match self {
+            Fold::X(at) => (Fold::fold_dim(x, *at), y),
+            Fold::Y(at) => (x, Fold::fold_dim(y, *at)),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct Paper {
+    dots: HashSet<(i32, i32)>,
+    folds: Vec<Fold>,
+}
+
+impl Paper {
+    fn fold_first(&self) -> HashSet<(i32, i32)> {
+        let fold = &self.folds[0];
+
+        let dots: HashSet<_> = self.dots.iter().map(|dot| fold.exec(*dot)).collect();
+
+        dots
+    }
+
+    fn fold_all(&self) -> HashSet<(i32, i32)> {
+        if self.folds.is_empty() {
+            return self.dots.clone();
+        }
+
+        let dots = self.fold_first();
+        let folds = Vec::from(&self.folds[1..]);
+
+        let folded = Paper { dots, folds };
+
+        folded.fold_all()
+    }
+}
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    const DAY: u32 = 13;
+    type Input = Paper;
+    type Part1 = usize;
+    type Part2 = String;
+
+    fn parse(input: &str) -> Result<Paper> {
+        let mut lines = input.lines().enumerate();
+        let dots = lines
+            .by_ref()
+            .take_while(|(_, l)| !l.is_empty())
+            .map(|(i, l)| parse_dot(l).map_err(|error| LineError { line: i + 1, error }))
+            .collect::<std::result::Result<_, _>>()?;
+        let folds: Vec<Fold> = lines
+            .map(|(i, l)| l.parse().map_err(|error| LineError { line: i + 1, error }))
+            .collect::<std::result::Result<_, _>>()?;
+        if folds.is_empty() {
+            anyhow::bail!("expected dots and folds separated by a blank line");
+        }
+
+        Ok(Paper { dots, folds })
+    }
+
+    fn part1(paper: &Paper) -> usize {
+        paper.fold_first().len()
+    }
+
+    /// The folded paper drawn with `#` for the dots, which spell out the answer.
+    fn part2(paper: &Paper) -> String {
+        render::text(paper.fold_all(), '#', ' ')
+    }
+}
+
+/// The paper before any folds and after each one.
+pub fn draw(paper: &Paper) -> Vec<Picture> {
+    let picture = |dots: &HashSet<(i32, i32)>| {
+        Picture::from_points(dots.iter().copied(), Rgb::WHITE, Rgb::BLACK)
+    };
+    let mut dots = paper.dots.clone();
+    let mut frames = vec![picture(&dots)];
+    for fold in &paper.folds {
+        dots = dots.iter().map(|&dot| fold.exec(dot)).collect();
+        frames.push(picture(&dots));
+    }
+    frames
+}
+
+#[cfg(test)]
+mod test {
+    use crate::*;
+
+    #[test]
+    fn parse_errors() {
+        assert_eq!(Ok(Fold::Y(7)), "fold along y=7".parse());
+        assert_eq!(
+            Err(ParseFoldError::Instruction {
+                column: 6,
+                token: "over".to_string()
+            }),
+            "fold over y=7".parse::<Fold>()
+        );
+        assert_eq!(
+            Err(ParseFoldError::Axis {
+                column: 12,
+                token: "z=7".to_string()
+            }),
+            "fold along z=7".parse::<Fold>()
+        );
+        assert_eq!(
+            Err(ParseFoldError::Position {
+                column: 14,
+                token: "seven".to_string()
+            }),
+            "fold along x=seven".parse::<Fold>()
+        );
+        assert_eq!(
+            Err(ParseDotError::Coordinate {
+                column: 4,
+                token: "y".to_string()
+            }),
+            parse_dot("10,y")
+        );
+
+        let err = Day13::parse("6,10\n0,14\n\nfold along y=7\nfold along x\n").unwrap_err();
+        assert_eq!(
+            "line 5, column 12: expected x=<n> or y=<n>, found \"x\"",
+            err.to_string()
+        );
+    }
+}
@@ -0,0 +1,168 @@
+use anyhow::{anyhow, Result};
+use aoc::parse::{self, complete, many1, pair, tag, terminated, Failure, Parser};
+use aoc::{LineError, Solution};
+use std::collections::HashMap;
+use std::iter::once;
+
+/// The polymer template and the pair insertion rules.
+#[derive(Debug)]
+pub struct Manual {
+    template: Vec<char>,
+    rules: HashMap<(char, char), char>,
+}
+
+/// An element of a polymer, which is a capital letter.
+fn element<'a>() -> impl Parser<'a, char> {
+    move |input: &'a str| match input.chars().next() {
+        Some(ch) if ch.is_ascii_uppercase() => Ok((ch, &input[1..])),
+        _ => Err(Failure::new(input, "an element")),
+    }
+}
+
+/// A pair insertion rule, like `CH -> B`.
+fn rule<'a>() -> impl Parser<'a, ((char, char), char)> {
+    pair(
+        terminated(pair(element(), element()), tag(" -> ")),
+        element(),
+    )
+}
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    const DAY: u32 = 14;
+    type Input = Manual;
+    type Part1 = usize;
+    type Part2 = u64;
+
+    fn parse(input: &str) -> Result<Manual> {
+        let mut sections = parse::sections(input);
+        let (line, template) = sections
+            .next()
+            .ok_or_else(|| anyhow!("expected a polymer template"))?;
+        let (first, rules) = sections
+            .next()
+            .ok_or_else(|| anyhow!("expected pair insertion rules after a blank line"))?;
+
+        let template =
+            complete(many1(element()), template).map_err(|error| LineError { line, error })?;
+        let rules = rules
+            .lines()
+            .enumerate()
+            .map(|(i, l)| {
+                complete(rule(), l).map_err(|error| LineError {
+                    line: first + i,
+                    error,
+                })
+            })
+            .collect::<Result<_, _>>()?;
+
+        Ok(Manual { template, rules })
+    }
+
+    fn part1(manual: &Manual) -> usize {
+        let mut polymer = manual.template.clone();
+        for _ in 0..10 {
+            polymer = step(&polymer, &manual.rules).collect();
+        }
+
+        let counts = polymer.iter().fold(HashMap::new(), |mut acc, ch| {
+            *acc.entry(ch).or_insert(0) += 1;
+            acc
+        });
+
+        let max_count = counts.values().max().unwrap();
+        let min_count = counts.values().min().unwrap();
+
+        max_count - min_count
+    }
+
+    fn part2(manual: &Manual) -> u64 {
+        let template = &manual.template;
+        let mut pair_counts = HashMap::new();
+
+        template.windows(2).for_each(|chs| {
+            let pair = (chs[0], chs[1]);
+            *pair_counts.entry(pair).or_insert(0) += 1u64;
+        });
+
+        for _ in 0..40 {
+            let mut next = HashMap::new();
+            for (&(a, b), &count) in &pair_counts {
+                // A pair with no rule stays as it is
+                match manual.rules.get(&(a, b)) {
+                    Some(&c) => {
+                        *next.entry((a, c)).or_insert(0) += count;
+                        *next.entry((c, b)).or_insert(0) += count;
+                    }
+                    None => *next.entry((a, b)).or_insert(0) += count,
+                }
+            }
+            pair_counts = next;
+        }
+
+        let mut counts = pair_counts
+            .iter()
+            .map(|(pair, count)| (pair.0, count))
+            .fold(HashMap::new(), |mut acc, (ch, count)| {
+                *acc.entry(ch).or_insert(0) += count;
+                acc
+            });
+
+        *counts.entry(template[template.len() - 1]).or_insert(0) += 1;
+
+        let max_count = counts.values().max().unwrap();
+        let min_count = counts.values().min().unwrap();
+
+        max_count - min_count
+    }
+}
+
+fn step<'a>(
+    s: &'a [char],
+    rules: &'a HashMap<(char, char), char>,
+) -> impl Iterator<Item = char> + 'a {
+    s.windows(2)
+        .flat_map(|chs| once(chs[0]).chain(rules.get(&(chs[0], chs[1])).copied()))
+        .chain(once(s[s.len() - 1]))
+}
+
+#[cfg(test)]
+mod test {
+    use crate::*;
+
+    #[test]
+    fn parse_errors() {
+        let manual = Day14::parse("NNCB\n\nCH -> B\nHH -> N\n").unwrap();
+        assert_eq!(vec!['N', 'N', 'C', 'B'], manual.template);
+        assert_eq!(Some(&'B'), manual.rules.get(&('C', 'H')));
+
+        let err = Day14::parse("NNCB\n\nCH -> B\nHH -> NN\n").unwrap_err();
+        assert_eq!(
+            "line 4, column 8: expected the end of the line, found \"N\"",
+            err.to_string()
+        );
+        let err = Day14::parse("NNCB\n\nC -> B\n").unwrap_err();
+        assert_eq!(
+            "line 3, column 2: expected an element, found ' '",
+            err.to_string()
+        );
+        let err = Day14::parse("NnCB\n\nCH -> B\n").unwrap_err();
+        assert_eq!(
+            "line 1, column 2: expected the end of the line, found \"nCB\"",
+            err.to_string()
+        );
+    }
+
+    #[test]
+    fn pairs_without_rules_stay() {
+        let manual = Day14::parse("NNC\n\nNN -> C\n").unwrap();
+        assert_eq!(
+            "NCNC",
+            step(&manual.template, &manual.rules).collect::<String>()
+        );
+        // Only NN has a rule, so after one step the polymer stays NCNC, evenly split
+        assert_eq!(0, Day14::part1(&manual));
+        assert_eq!(0, Day14::part2(&manual));
+    }
+}
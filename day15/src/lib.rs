@@ -0,0 +1,113 @@
+use anyhow::{bail, Result};
+use aoc::Solution;
+use grid::Grid;
+use render::{Picture, Rgb};
+use std::str::FromStr;
+
+#[derive(Debug)]
+pub struct Cave {
+    risk: Grid<u32>,
+}
+
+impl Cave {
+    fn path_dist(
+        &self,
+        start: (usize, usize),
+        end: (usize, usize),
+    ) -> Option<(u32, Vec<(usize, usize)>)> {
+        let search = search::dijkstra(
+            start,
+            |&p| self.risk.neighbours4(p).map(|n| (n, self.risk[n])),
+            |&p| p == end,
+        );
+        Some((search.goal_cost()?, search.goal_path()?))
+    }
+}
+
+impl FromStr for Cave {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let risk: Grid<u32> = s.parse()?;
+        // With no corners there's no path to find
+        if risk.width() == 0 || risk.height() == 0 {
+            bail!("the cave map is empty");
+        }
+        Ok(Cave { risk })
+    }
+}
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    const DAY: u32 = 15;
+    type Input = Cave;
+    type Part1 = u32;
+    type Part2 = u32;
+
+    fn parse(input: &str) -> Result<Cave> {
+        input.parse()
+    }
+
+    fn part1(cave: &Cave) -> u32 {
+        //Not 442
+        lowest_risk(cave)
+    }
+
+    fn part2(cave: &Cave) -> u32 {
+        lowest_risk(&Cave {
+            risk: expand_grid(&cave.risk),
+        })
+    }
+}
+
+/// The lowest total risk of any path from the top left to the bottom right.
+fn lowest_risk(cave: &Cave) -> u32 {
+    safest_path(cave).0
+}
+
+/// The least risky path from the top left to the bottom right, and its total risk.
+fn safest_path(cave: &Cave) -> (u32, Vec<(usize, usize)>) {
+    let target = (cave.risk.width() - 1, cave.risk.height() - 1);
+    cave.path_dist((0, 0), target).expect("no path")
+}
+
+/// The cave's risk levels as a heatmap, with the safest path across it in white.
+pub fn draw(cave: &Cave) -> Vec<Picture> {
+    let mut picture = Picture::heatmap(&cave.risk);
+    picture.highlight(safest_path(cave).1, Rgb::WHITE);
+    vec![picture]
+}
+
+fn wrap(n: u32) -> u32 {
+    if n > 9 {
+        n - 9
+    } else {
+        n
+    }
+}
+
+fn expand_grid(grid: &Grid<u32>) -> Grid<u32> {
+    let (w, h) = (grid.width(), grid.height());
+    Grid::from_fn(w * 5, h * 5, |(x, y)| {
+        wrap(grid[(x % w, y % h)] + (x / w + y / h) as u32)
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use crate::*;
+
+    #[test]
+    fn parse_errors() {
+        assert_eq!(
+            "the cave map is empty",
+            Day15::parse("").unwrap_err().to_string()
+        );
+        assert_eq!(
+            "line 2 has 1 cells, expected 2",
+            Day15::parse("12\n3").unwrap_err().to_string()
+        );
+        assert_eq!(0, Day15::part1(&Day15::parse("7").unwrap()));
+    }
+}
@@ -0,0 +1,352 @@
+use anyhow::Result;
+use aoc::Solution;
+use thiserror::Error;
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum Packet {
+    LITERAL {
+        version: u8,
+        value: u64,
+    },
+    OPERATOR {
+        version: u8,
+        type_id: u8,
+        packets: Vec<Packet>,
+    },
+}
+
+/// How an operator packet marks where its sub-packets end.
+#[derive(Debug, Clone, Copy)]
+enum Length {
+    /// The number of bits they take up.
+    Bits,
+    /// The number of them.
+    Count,
+}
+
+impl Packet {
+    /// The packet as a hexadecimal transmission, which parses back to the same packet. Operators
+    /// give the length of their sub-packets in bits, or as a count when that's too long to fit.
+    pub fn to_hex(&self) -> String {
+        let mut bits = Vec::new();
+        self.encode(&mut bits, Length::Bits);
+        // Pad out to whole bytes, as transmissions are
+        bits.resize(bits.len().div_ceil(8) * 8, false);
+        bits.chunks(4)
+            .map(|digit| {
+                let n = digit.iter().fold(0, |acc, &b| acc << 1 | b as u32);
+                char::from_digit(n, 16).unwrap().to_ascii_uppercase()
+            })
+            .collect()
+    }
+
+    fn encode(&self, out: &mut Vec<bool>, length: Length) {
+        match self {
+            Packet::LITERAL { version, value } => {
+                push_number(out, *version as u64, 3);
+                push_number(out, 4, 3);
+                let groups = (u64::BITS - value.leading_zeros()).div_ceil(4).max(1);
+                for group in (0..groups).rev() {
+                    push_number(out, (group > 0) as u64, 1);
+                    push_number(out, value >> (4 * group) & 0xf, 4);
+                }
+            }
+            Packet::OPERATOR {
+                version,
+                type_id,
+                packets,
+            } => {
+                push_number(out, *version as u64, 3);
+                push_number(out, *type_id as u64, 3);
+                let mut sub_bits = Vec::new();
+                for p in packets {
+                    p.encode(&mut sub_bits, length);
+                }
+                // Too many bits to give in 15 falls back to counting the packets
+                let length = match length {
+                    Length::Bits if sub_bits.len() < 1 << 15 => Length::Bits,
+                    _ => Length::Count,
+                };
+                match length {
+                    Length::Bits => {
+                        push_number(out, 0, 1);
+                        push_number(out, sub_bits.len() as u64, 15);
+                    }
+                    Length::Count => {
+                        push_number(out, 1, 1);
+                        push_number(out, packets.len() as u64, 11);
+                    }
+                }
+                out.extend(sub_bits);
+            }
+        }
+    }
+
+    pub fn value(&self) -> u64 {
+        match self {
+            Packet::LITERAL { value, .. } => *value,
+            Packet::OPERATOR {
+                type_id, packets, ..
+            } => match type_id {
+                0 => packets.iter().map(|p| p.value()).sum(),
+                1 => packets.iter().map(|p| p.value()).product(),
+                2 => packets.iter().map(|p| p.value()).min().unwrap(),
+                3 => packets.iter().map(|p| p.value()).max().unwrap(),
+                5 => {
+                    assert_eq!(2, packets.len());
+                    if packets[0].value() > packets[1].value() {
+                        1
+                    } else {
+                        0
+                    }
+                }
+                6 => {
+                    assert_eq!(2, packets.len());
+                    if packets[0].value() < packets[1].value() {
+                        1
+                    } else {
+                        0
+                    }
+                }
+                7 => {
+                    assert_eq!(2, packets.len());
+                    if packets[0].value() == packets[1].value() {
+                        1
+                    } else {
+                        0
+                    }
+                }
+
+                _ => panic!("Unimplemented operator type {}", type_id),
+            },
+        }
+    }
+}
+
+/// Appends the low `n` bits of `value`, most significant first.
+fn push_number(out: &mut Vec<bool>, value: u64, n: u64) {
+    out.extend((0..n).rev().map(|i| value >> i & 1 == 1));
+}
+
+/// Why a transmission isn't a packet. Columns count from 1, a hexadecimal digit at a time.
+#[derive(Clone, Debug, Eq, PartialEq, Error)]
+pub enum ParsePacketError {
+    #[error("column {column}: expected a hexadecimal digit, found {token:?}")]
+    Digit { column: usize, token: char },
+    #[error("column {column}: a packet is cut off before it ends")]
+    Truncated { column: usize },
+    #[error("the transmission is empty")]
+    Empty,
+}
+
+/// The bits of a transmission still to be read, and how many came before them.
+struct Bits<'a> {
+    bits: &'a [bool],
+    read: usize,
+}
+
+impl<'a> Bits<'a> {
+    fn new(bits: &'a [bool]) -> Self {
+        Bits { bits, read: 0 }
+    }
+
+    fn is_empty(&self) -> bool {
+        self.bits.is_empty()
+    }
+
+    /// Takes the next `n` bits, or fails if there aren't that many left.
+    fn take(&mut self, n: usize) -> Result<Bits<'a>, ParsePacketError> {
+        if n > self.bits.len() {
+            // Point at the digit holding the first missing bit
+            return Err(ParsePacketError::Truncated {
+                column: (self.read + self.bits.len()) / 4 + 1,
+            });
+        }
+        let (taken, rest) = self.bits.split_at(n);
+        let taken = Bits {
+            bits: taken,
+            read: self.read,
+        };
+        self.bits = rest;
+        self.read += n;
+        Ok(taken)
+    }
+
+    /// The next `n` bits as a number, most significant first.
+    fn number(&mut self, n: usize) -> Result<u64, ParsePacketError> {
+        let taken = self.take(n)?;
+        Ok(taken.bits.iter().fold(0, |acc, &b| acc << 1 | b as u64))
+    }
+}
+
+fn literal(bits: &mut Bits) -> Result<u64, ParsePacketError> {
+    let mut ret: u64 = 0;
+    let mut last = false;
+    while !last {
+        let word = bits.number(5)?;
+        last = word & 0x10 == 0;
+        ret = (ret << 4) | (word & 0xf);
+    }
+    Ok(ret)
+}
+
+fn operator(bits: &mut Bits) -> Result<Vec<Packet>, ParsePacketError> {
+    let mut ret = Vec::new();
+    if bits.number(1)? == 0 {
+        let length = bits.number(15)? as usize;
+        let mut sub_bits = bits.take(length)?;
+        while !sub_bits.is_empty() {
+            ret.push(packet(&mut sub_bits)?);
+        }
+    } else {
+        let sub_packets = bits.number(11)? as usize;
+        for _ in 0..sub_packets {
+            ret.push(packet(bits)?);
+        }
+    }
+    Ok(ret)
+}
+
+fn packet(bits: &mut Bits) -> Result<Packet, ParsePacketError> {
+    let version = bits.number(3)? as u8;
+    let type_id = bits.number(3)? as u8;
+    match type_id {
+        4 => Ok(Packet::LITERAL {
+            version,
+            value: literal(bits)?,
+        }),
+        _ => Ok(Packet::OPERATOR {
+            version,
+            type_id,
+            packets: operator(bits)?,
+        }),
+    }
+}
+
+fn version_sum(packets: &[Packet]) -> u64 {
+    packets
+        .iter()
+        .map(|p| match p {
+            Packet::LITERAL { version, .. } => *version as u64,
+            Packet::OPERATOR {
+                version,
+                packets: sub_packets,
+                ..
+            } => *version as u64 + version_sum(sub_packets),
+        })
+        .sum()
+}
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    const DAY: u32 = 16;
+    type Input = Packet;
+    type Part1 = u64;
+    type Part2 = u64;
+
+    fn parse(input: &str) -> Result<Packet> {
+        let mut bits = Vec::new();
+        for (i, ch) in input.trim().chars().enumerate() {
+            let digit = ch.to_digit(16).ok_or(ParsePacketError::Digit {
+                column: i + 1,
+                token: ch,
+            })?;
+            push_number(&mut bits, digit as u64, 4);
+        }
+        if bits.is_empty() {
+            return Err(ParsePacketError::Empty.into());
+        }
+
+        // The transmission is a single packet padded out with zeros, so stop after it rather than
+        // reading the padding as the start of another
+        Ok(packet(&mut Bits::new(&bits))?)
+    }
+
+    fn part1(packet: &Packet) -> u64 {
+        version_sum(std::slice::from_ref(packet))
+    }
+
+    fn part2(packet: &Packet) -> u64 {
+        packet.value()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::*;
+    use proptest::prelude::*;
+
+    /// Packets nested a few deep, with any literal values and operators of any type and arity.
+    fn any_packet() -> impl Strategy<Value = Packet> {
+        let literal =
+            (0u8..8, any::<u64>()).prop_map(|(version, value)| Packet::LITERAL { version, value });
+        literal.prop_recursive(4, 32, 4, |inner| {
+            let type_id = prop::sample::select(vec![0u8, 1, 2, 3, 5, 6, 7]);
+            (0u8..8, type_id, prop::collection::vec(inner, 0..4)).prop_map(
+                |(version, type_id, packets)| Packet::OPERATOR {
+                    version,
+                    type_id,
+                    packets,
+                },
+            )
+        })
+    }
+
+    #[test]
+    fn examples_encode() {
+        let packet = Day16::parse("D2FE28").unwrap();
+        assert_eq!(
+            Packet::LITERAL {
+                version: 6,
+                value: 2021
+            },
+            packet
+        );
+        assert_eq!("D2FE28", packet.to_hex());
+        assert_eq!(
+            "38006F45291200",
+            Day16::parse("38006F45291200").unwrap().to_hex()
+        );
+    }
+
+    #[test]
+    fn malformed_transmissions() {
+        let error = |input: &str| {
+            Day16::parse(input)
+                .unwrap_err()
+                .downcast::<ParsePacketError>()
+                .unwrap()
+        };
+        assert_eq!(
+            ParsePacketError::Digit {
+                column: 5,
+                token: 'x'
+            },
+            error("D2FEx8")
+        );
+        // The literal's last group is missing its last bit
+        assert_eq!(ParsePacketError::Truncated { column: 6 }, error("D2FE2"));
+        // The operator gives 27 bits of sub-packets, but only 26 follow it
+        assert_eq!(
+            ParsePacketError::Truncated { column: 13 },
+            error("38006F452912")
+        );
+        assert_eq!(ParsePacketError::Empty, error(" \n"));
+    }
+
+    proptest! {
+        #[test]
+        fn packets_round_trip(packet in any_packet()) {
+            prop_assert_eq!(&packet, &Day16::parse(&packet.to_hex()).unwrap());
+        }
+
+        #[test]
+        fn counted_sub_packets_round_trip(packet in any_packet()) {
+            let mut bits = Vec::new();
+            packet.encode(&mut bits, Length::Count);
+            let parsed = crate::packet(&mut Bits::new(&bits)).unwrap();
+            prop_assert_eq!(packet, parsed);
+        }
+    }
+}
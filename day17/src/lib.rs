@@ -0,0 +1,91 @@
+use anyhow::Result;
+use aoc::parse::{complete, pair, preceded, range, tag};
+use aoc::Solution;
+use std::collections::HashMap;
+use vectory::{Aabb, IntVector};
+
+#[derive(Debug)]
+struct Probe {
+    pos: IntVector<2, i32>,
+    vel: IntVector<2, i32>,
+}
+
+impl Probe {
+    fn new(dx: i32, dy: i32) -> Probe {
+        Probe {
+            pos: IntVector::from([0, 0]),
+            vel: IntVector::from([dx, dy]),
+        }
+    }
+
+    fn step(&mut self) -> IntVector<2, i32> {
+        self.pos += self.vel;
+        // Drag pulls dx toward zero, gravity pulls dy down
+        self.vel -= IntVector::from([self.vel[0].signum(), 1]);
+
+        self.pos()
+    }
+
+    fn pos(&self) -> IntVector<2, i32> {
+        self.pos
+    }
+}
+
+pub type Target = Aabb<2, i32>;
+
+pub struct Day17;
+
+impl Solution for Day17 {
+    const DAY: u32 = 17;
+    type Input = Target;
+    type Part1 = i32;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Target> {
+        let area = pair(range("x"), preceded(tag(", "), range("y")));
+        let (x, y) = complete(preceded(tag("target area: "), area), input.trim_end())?;
+        Ok(Aabb::new(
+            IntVector::from([*x.start(), *y.start()]),
+            IntVector::from([*x.end(), *y.end()]),
+        ))
+    }
+
+    fn part1(target: &Target) -> i32 {
+        *launches(target).values().max().unwrap()
+    }
+
+    fn part2(target: &Target) -> usize {
+        launches(target).len()
+    }
+}
+
+/// Fires a probe at every launch velocity that could reach the target, which must lie below and
+/// to the right of the launcher, returning the highest point reached by each one that hits it.
+fn launches(target: &Target) -> HashMap<(i32, i32), i32> {
+    let floor = target.min[1];
+    let far_wall = target.max[0];
+
+    let mut records = HashMap::new();
+
+    // Anything launched up at dy comes back down through y=0 at -dy-1, so faster would
+    // overshoot the floor in one step
+    for dx in 1..=far_wall {
+        for dy in floor..-floor {
+            let mut p = Probe::new(dx, dy);
+            let mut pos = p.pos();
+            let v0 = (dx, dy);
+            let mut highest = 0;
+
+            while pos[0] <= far_wall && pos[1] >= floor {
+                pos = p.step();
+                if pos[1] > highest {
+                    highest = pos[1];
+                }
+                if target.contains(&pos) {
+                    records.insert(v0, highest);
+                }
+            }
+        }
+    }
+    records
+}
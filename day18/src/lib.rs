@@ -1,8 +1,11 @@
+use aoc::Solution;
+use itertools::Itertools;
 use std::fmt::{Display, Formatter};
 use std::mem;
 use std::str::FromStr;
+use thiserror::Error;
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Eq, PartialEq)]
 pub enum SnailNum {
     Reg(u32),
     Pair(Box<SnailNum>, Box<SnailNum>),
@@ -21,24 +24,68 @@ impl Display for SnailNum {
     }
 }
 
+/// Why a line isn't a snailfish number. Columns count from 1.
+#[derive(Clone, Debug, Eq, PartialEq, Error)]
+pub enum ParseSnailNumError {
+    #[error("column {column}: expected {expected}, found {token:?}")]
+    Unexpected {
+        column: usize,
+        expected: &'static str,
+        token: char,
+    },
+    #[error("column {column}: expected {expected}, found the end of the line")]
+    End {
+        column: usize,
+        expected: &'static str,
+    },
+}
+
 impl SnailNum {
-    fn pair(chs: &[char]) -> (SnailNum, usize) {
-        assert_eq!(&'[', &chs[0]);
-        let (left, off) = SnailNum::num(&chs[1..]);
-        assert_eq!(&',', &chs[off + 1]);
-        let (right, off2) = SnailNum::num(&chs[off + 2..]);
-        assert_eq!(&']', &chs[off + 2 + off2]);
-        (
-            SnailNum::Pair(Box::new(left), Box::new(right)),
-            off + 3 + off2,
-        )
-    }
-
-    fn num(chs: &[char]) -> (SnailNum, usize) {
-        match &chs[0] {
-            '[' => SnailNum::pair(chs),
-            '0'..='9' => (SnailNum::Reg(*&chs[0].to_digit(10).unwrap()), 1),
-            ch => panic!("Unexpected {}", ch),
+    /// Checks that `chs` has `ch` at `at`, described as `expected` if it doesn't.
+    fn expect(
+        chs: &[char],
+        at: usize,
+        ch: char,
+        expected: &'static str,
+    ) -> Result<(), ParseSnailNumError> {
+        match chs.get(at) {
+            Some(&c) if c == ch => Ok(()),
+            Some(&token) => Err(ParseSnailNumError::Unexpected {
+                column: at + 1,
+                expected,
+                token,
+            }),
+            None => Err(ParseSnailNumError::End {
+                column: at + 1,
+                expected,
+            }),
+        }
+    }
+
+    /// Parses the pair starting at `at` in `chs`, returning it and the position just past it.
+    fn pair(chs: &[char], at: usize) -> Result<(SnailNum, usize), ParseSnailNumError> {
+        SnailNum::expect(chs, at, '[', "\"[\"")?;
+        let (left, at) = SnailNum::num(chs, at + 1)?;
+        SnailNum::expect(chs, at, ',', "\",\"")?;
+        let (right, at) = SnailNum::num(chs, at + 1)?;
+        SnailNum::expect(chs, at, ']', "\"]\"")?;
+        Ok((SnailNum::Pair(Box::new(left), Box::new(right)), at + 1))
+    }
+
+    fn num(chs: &[char], at: usize) -> Result<(SnailNum, usize), ParseSnailNumError> {
+        const EXPECTED: &str = "a digit or a pair";
+        match chs.get(at) {
+            Some('[') => SnailNum::pair(chs, at),
+            Some(&ch @ '0'..='9') => Ok((SnailNum::Reg(ch.to_digit(10).unwrap()), at + 1)),
+            Some(&token) => Err(ParseSnailNumError::Unexpected {
+                column: at + 1,
+                expected: EXPECTED,
+                token,
+            }),
+            None => Err(ParseSnailNumError::End {
+                column: at + 1,
+                expected: EXPECTED,
+            }),
         }
     }
 
@@ -153,19 +200,54 @@ impl SnailNum {
 }
 
 impl FromStr for SnailNum {
-    type Err = anyhow::Error;
+    type Err = ParseSnailNumError;
 
-    fn from_str(s: &str) -> anyhow::Result<Self, Self::Err> {
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
         let chs: Vec<char> = s.chars().collect();
-        let (sn, off) = SnailNum::pair(&chs);
-        assert_eq!(chs.len(), off);
+        let (sn, end) = SnailNum::pair(&chs, 0)?;
+        if let Some(&token) = chs.get(end) {
+            return Err(ParseSnailNumError::Unexpected {
+                column: end + 1,
+                expected: "the end of the line",
+                token,
+            });
+        }
         Ok(sn)
     }
 }
 
+pub struct Day18;
+
+impl Solution for Day18 {
+    const DAY: u32 = 18;
+    type Input = Vec<SnailNum>;
+    type Part1 = u32;
+    type Part2 = u32;
+
+    fn parse(input: &str) -> anyhow::Result<Vec<SnailNum>> {
+        Ok(aoc::parse_lines(input)?)
+    }
+
+    fn part1(input: &Vec<SnailNum>) -> u32 {
+        let added = input.iter().cloned().reduce(|a, b| a.add(&b)).unwrap();
+        added.magnitude()
+    }
+
+    fn part2(input: &Vec<SnailNum>) -> u32 {
+        input
+            .iter()
+            .tuple_combinations()
+            .flat_map(|(a, b)| [a.add(b), b.add(a)])
+            .map(|n| n.magnitude())
+            .max()
+            .unwrap()
+    }
+}
+
 #[cfg(test)]
 mod test {
     use crate::*;
+    use proptest::prelude::*;
 
     fn parse(s: &str) -> Vec<SnailNum> {
         s.lines().map(|l| l.parse().unwrap()).collect()
@@ -193,4 +275,94 @@ mod test {
 
         assert_eq!("[[[[5,0],[7,4]],[5,5]],[6,6]]", &added.to_string())
     }
+
+    #[test]
+    fn parse_errors() {
+        assert_eq!(
+            Err(ParseSnailNumError::Unexpected {
+                column: 4,
+                expected: "\",\"",
+                token: ']'
+            }),
+            "[[1]]".parse::<SnailNum>()
+        );
+        assert_eq!(
+            Err(ParseSnailNumError::Unexpected {
+                column: 7,
+                expected: "a digit or a pair",
+                token: 'x'
+            }),
+            "[1,[2,x]]".parse::<SnailNum>()
+        );
+        assert_eq!(
+            Err(ParseSnailNumError::End {
+                column: 5,
+                expected: "\"]\""
+            }),
+            "[1,2".parse::<SnailNum>()
+        );
+        assert_eq!(
+            Err(ParseSnailNumError::Unexpected {
+                column: 6,
+                expected: "the end of the line",
+                token: '['
+            }),
+            "[1,2][3,4]".parse::<SnailNum>()
+        );
+    }
+
+    fn pair(left: SnailNum, right: SnailNum) -> SnailNum {
+        SnailNum::Pair(Box::new(left), Box::new(right))
+    }
+
+    /// Reduced elements nested inside at most `pairs` pairs of their own.
+    fn element(pairs: u32) -> impl Strategy<Value = SnailNum> {
+        (0u32..10)
+            .prop_map(SnailNum::Reg)
+            .prop_recursive(pairs, 1 << pairs, 2, |inner| {
+                (inner.clone(), inner).prop_map(|(l, r)| pair(l, r))
+            })
+    }
+
+    /// Numbers with pairs nested at most `depth` deep, counting the outermost.
+    fn number(depth: u32) -> impl Strategy<Value = SnailNum> {
+        (element(depth - 1), element(depth - 1)).prop_map(|(l, r)| pair(l, r))
+    }
+
+    /// Whether nothing in `num`, found `depth` pairs deep, would explode or split.
+    fn is_reduced(num: &SnailNum, depth: usize) -> bool {
+        match num {
+            SnailNum::Reg(n) => *n < 10,
+            SnailNum::Pair(l, r) => {
+                depth < 4 && is_reduced(l, depth + 1) && is_reduced(r, depth + 1)
+            }
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn prints_and_parses_back(num in number(4)) {
+            prop_assert_eq!(Ok(num.clone()), num.to_string().parse());
+        }
+
+        #[test]
+        fn reducing_is_idempotent(num in number(4)) {
+            prop_assert!(is_reduced(&num, 0));
+            prop_assert_eq!(num.clone().reduce(), num);
+        }
+
+        #[test]
+        fn sums_are_reduced(a in number(4), b in number(4)) {
+            let sum = a.add(&b);
+            prop_assert!(is_reduced(&sum, 0));
+            prop_assert_eq!(sum.clone().reduce(), sum);
+        }
+
+        #[test]
+        fn shallow_sums_are_just_pairs(a in number(3), b in number(3)) {
+            let sum = a.add(&b);
+            prop_assert_eq!(3 * a.magnitude() + 2 * b.magnitude(), sum.magnitude());
+            prop_assert_eq!(pair(a, b), sum);
+        }
+    }
 }
@@ -1,127 +1,5 @@
 use anyhow::Result;
-use itertools::Itertools;
-use lib::SnailNum;
-
-mod lib;
 
 fn main() -> Result<()> {
-    let input: Vec<SnailNum> = INPUT.lines().map(|l| l.parse().unwrap()).collect();
-
-    let added = input.clone().into_iter().reduce(|a, b| a.add(&b)).unwrap();
-
-    println!("Part 1 {}", added.magnitude());
-
-    let max_pair_sum = input
-        .into_iter()
-        .tuple_combinations()
-        .flat_map(|(a, b)| [a.add(&b), b.add(&a)])
-        .map(|n| n.magnitude())
-        .max()
-        .unwrap();
-
-    println!("Part 2 {}", max_pair_sum);
-
-    Ok(())
+    aoc::run::<day18::Day18>()
 }
-
-const INPUT: &str = r#"[[7,[8,[3,5]]],[[[3,6],9],1]]
-[[[[1,7],8],[0,4]],[[[0,9],2],[2,[5,6]]]]
-[[[4,[4,4]],[8,[4,4]]],[[5,4],6]]
-[[[1,[1,3]],[[9,6],1]],[[4,[5,4]],[[4,4],[0,8]]]]
-[[[[6,2],[2,5]],[2,1]],[[1,5],7]]
-[[[[5,0],[2,7]],[[2,5],2]],[2,[3,2]]]
-[[[6,[6,6]],[0,[2,8]]],[[8,[5,6]],[4,5]]]
-[[[6,[3,5]],8],[[2,[7,0]],5]]
-[[[[7,8],[3,6]],[1,6]],[[[4,2],1],[[0,7],[5,6]]]]
-[8,2]
-[[5,5],[[2,[9,1]],8]]
-[[[4,8],[[1,8],2]],[9,2]]
-[2,[[8,[8,3]],[0,6]]]
-[[[6,6],[[6,0],6]],[0,[[3,4],3]]]
-[[[[2,9],[5,9]],[2,[4,3]]],[6,0]]
-[[[6,2],0],[7,7]]
-[[[9,6],5],[2,[[0,1],[5,5]]]]
-[[6,[[0,1],[5,1]]],5]
-[4,[[[4,2],3],[2,[5,0]]]]
-[[[7,9],2],2]
-[[[5,[2,1]],1],[[1,1],[8,5]]]
-[[[[5,9],0],[[1,9],0]],4]
-[[7,[0,5]],[[0,3],[8,2]]]
-[[6,[9,[7,7]]],6]
-[2,[[1,[1,0]],[4,[6,1]]]]
-[[0,6],[[[5,1],6],[[4,7],[8,0]]]]
-[[[1,[4,7]],[0,[1,2]]],[[1,1],[[1,2],[1,3]]]]
-[[8,[3,0]],[3,[1,[8,1]]]]
-[[[7,[4,0]],[[8,7],2]],[[7,[7,3]],7]]
-[3,[[1,7],2]]
-[8,[[[1,5],0],1]]
-[[6,[1,4]],7]
-[[[[2,6],2],8],1]
-[9,7]
-[9,[[[1,1],1],[[3,0],[7,3]]]]
-[[[[8,5],7],[[5,1],[6,4]]],[4,[[7,6],[2,7]]]]
-[[[[8,7],1],0],[[9,9],[[1,0],8]]]
-[[9,[[1,1],7]],[[3,0],4]]
-[[[[8,2],4],[9,[7,9]]],[[0,2],[[3,0],5]]]
-[[[[3,6],3],[[9,7],[0,6]]],[[[4,9],[1,3]],[2,[7,3]]]]
-[[[[3,8],0],[[3,6],5]],[[3,[4,2]],[[6,1],[8,5]]]]
-[[2,7],[[0,0],8]]
-[[[[0,3],7],[2,0]],3]
-[[0,2],[[[3,1],0],[0,0]]]
-[[[[6,1],7],[[8,4],[2,4]]],[[1,6],[2,3]]]
-[[2,[2,[9,1]]],[4,[[0,4],9]]]
-[[[3,[5,6]],7],1]
-[[[3,0],[8,[9,3]]],[[[1,1],1],[6,7]]]
-[[[6,[4,4]],[[1,9],1]],[[[8,1],[9,8]],[[6,3],1]]]
-[[[5,8],[[0,3],[1,7]]],[[[3,2],[4,7]],1]]
-[[5,5],[[[8,3],[6,6]],2]]
-[[[[1,9],[8,5]],[[7,7],8]],[0,[8,[7,4]]]]
-[[6,[4,[4,3]]],[5,[6,[7,2]]]]
-[[0,[[9,0],0]],5]
-[[[[5,6],[1,3]],[[0,5],[7,5]]],[[[0,4],[3,6]],[8,[3,6]]]]
-[[3,[[4,7],[7,0]]],[[[4,1],5],[[6,6],[7,4]]]]
-[[[[4,3],[0,1]],[[7,3],[2,3]]],[[[3,7],[2,2]],[6,5]]]
-[[1,1],[[[1,4],6],[6,[3,9]]]]
-[[[[0,8],[2,0]],5],[4,[[6,1],[2,1]]]]
-[[7,[3,[7,2]]],[[7,9],8]]
-[[[4,[9,8]],[8,[3,2]]],[7,9]]
-[[[4,[4,2]],[5,[0,3]]],[[[4,9],[2,9]],[[1,5],[0,8]]]]
-[[1,[[9,8],0]],[5,[[4,3],5]]]
-[[[[5,1],3],[[2,9],[9,0]]],[1,[6,3]]]
-[[[6,4],[6,1]],7]
-[[4,8],[[7,2],6]]
-[[[5,[4,8]],[[1,7],[2,8]]],[6,[[8,4],[3,5]]]]
-[[5,8],[[[4,0],[6,0]],[5,[6,0]]]]
-[[3,[[5,3],8]],[8,5]]
-[[[2,6],[1,[2,3]]],[[[1,7],[5,7]],[[0,0],[0,5]]]]
-[[0,[[4,3],[3,6]]],[[2,[6,6]],[0,[2,9]]]]
-[[[5,9],[6,2]],[[7,6],8]]
-[[9,2],[1,[[0,5],[5,0]]]]
-[[[3,1],[9,3]],3]
-[[[[2,0],[4,2]],6],[[[5,2],[7,8]],[[0,7],3]]]
-[[7,[[3,9],[6,3]]],[2,[[6,4],3]]]
-[[5,[3,[7,4]]],[[2,5],[0,9]]]
-[3,7]
-[[3,9],[[[4,4],9],[[3,1],7]]]
-[[[[4,0],1],[8,[3,6]]],[[9,[4,4]],[[9,9],9]]]
-[[8,[[8,1],5]],[[[9,1],4],[[8,5],3]]]
-[[6,[[6,3],[3,7]]],4]
-[[[1,[0,8]],9],[[8,5],[3,[0,5]]]]
-[[[[3,1],0],[[8,5],[1,0]]],[0,2]]
-[[2,[4,7]],2]
-[[[2,0],[2,2]],4]
-[4,[[5,8],5]]
-[[[2,[0,5]],[[3,3],[6,6]]],1]
-[[[2,[2,4]],[5,[7,1]]],[3,5]]
-[[2,[9,[3,9]]],9]
-[[[7,[7,1]],[[5,2],1]],[[2,1],[9,[7,3]]]]
-[[4,[4,6]],4]
-[[[4,2],[9,[3,8]]],[[2,4],0]]
-[[[7,[0,3]],4],[[[1,8],4],[[5,1],1]]]
-[[[1,3],3],[[4,9],[[0,0],6]]]
-[[[4,1],0],[[[5,6],[0,8]],[[2,1],1]]]
-[[3,[3,[7,9]]],[[[6,8],8],[[7,9],3]]]
-[[4,[[1,6],[4,6]]],[[1,8],[3,8]]]
-[[[[5,9],2],[[6,7],4]],3]
-[[[[2,1],[1,9]],7],[[[0,9],[0,5]],[[2,5],[5,0]]]]
-"#;
@@ -0,0 +1,92 @@
+use anyhow::Result;
+use aoc::{parse, LineError, Solution};
+use itertools::Itertools;
+use std::collections::{HashMap, HashSet};
+use vectory::{registration, Affine, IntVector, Matrix, Vector};
+
+// All the possible rotations
+const ROTATIONS: [Matrix<3>; 24] = Matrix::<3>::orientation_table(false);
+
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub struct Report {
+    name: String,
+    beacons: HashSet<IntVector<3>>,
+}
+
+impl Report {
+    /// Merges all reports into the frame of the first, returning the merged report and the pose
+    /// of each scanner relative to scanner 0.
+    fn realign(reports: &[Report]) -> (Report, HashMap<&str, Affine<3>>) {
+        let clouds: Vec<HashSet<IntVector<3>>> =
+            reports.iter().map(|r| r.beacons.clone()).collect();
+        let alignment = registration::realign(&clouds, &ROTATIONS, 12);
+        assert!(
+            alignment.unplaced.is_empty(),
+            "unplaced scanners: {:?}",
+            alignment.unplaced
+        );
+
+        let mut ret = reports[0].clone();
+        let mut poses = HashMap::with_capacity(reports.len());
+        for (report, pose) in reports.iter().zip(alignment.poses) {
+            let pose = pose.unwrap();
+            ret.beacons
+                .extend(pose.apply_all::<_, Vec<_>>(&report.beacons));
+            poses.insert(report.name.as_str(), pose);
+        }
+
+        (ret, poses)
+    }
+}
+
+fn find_max_distance(vs: &[IntVector<3>]) -> i64 {
+    vs.iter()
+        .tuple_combinations()
+        .map(|(x, y)| x.manh_dist(y))
+        .max()
+        .unwrap()
+}
+
+pub struct Day19;
+
+impl Solution for Day19 {
+    const DAY: u32 = 19;
+    type Input = Vec<Report>;
+    type Part1 = usize;
+    type Part2 = i64;
+
+    fn parse(input: &str) -> Result<Vec<Report>> {
+        parse::sections(input)
+            .map(|(first, report)| {
+                let mut lines = report.lines();
+                let name = lines.next().unwrap_or_default();
+                let beacons = lines
+                    .enumerate()
+                    .map(|(i, l)| {
+                        l.parse().map_err(|error| LineError {
+                            line: first + 1 + i,
+                            error,
+                        })
+                    })
+                    .collect::<Result<_, _>>()?;
+
+                Ok(Report {
+                    name: name.to_string(),
+                    beacons,
+                })
+            })
+            .collect()
+    }
+
+    fn part1(reports: &Vec<Report>) -> usize {
+        let (aligned, _) = Report::realign(reports);
+        aligned.beacons.len()
+    }
+
+    fn part2(reports: &Vec<Report>) -> i64 {
+        let (_, poses) = Report::realign(reports);
+        let sensor_coords: Vec<IntVector<3>> = poses.values().map(|p| p.translation).collect();
+
+        find_max_distance(&sensor_coords)
+    }
+}
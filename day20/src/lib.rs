@@ -0,0 +1,193 @@
+use anyhow::{anyhow, Context, Result};
+use aoc::parse::{self, complete, either, many1, map, tag, ParseError, Parser};
+use aoc::{LineError, Solution};
+use grid::{Boundary, Grid};
+use render::{Picture, Rgb};
+use sim::{Driver, Simulation};
+
+#[derive(Debug, Clone)]
+struct Image {
+    grid: Grid<bool>,
+}
+
+impl Image {
+    const OFFSETS: [(i64, i64); 9] = [
+        (-1, -1),
+        (0, -1),
+        (1, -1),
+        (-1, 0),
+        (0, 0),
+        (1, 0),
+        (-1, 1),
+        (0, 1),
+        (1, 1),
+    ];
+
+    fn default_value(&self) -> bool {
+        self.grid.boundary() == &Boundary::Default(true)
+    }
+
+    fn convolved(&self, pos: (i64, i64)) -> usize {
+        number(
+            &mut Image::OFFSETS
+                .iter()
+                .map(|off| *self.grid.at((off.0 + pos.0, off.1 + pos.1)).unwrap()),
+            Image::OFFSETS.len(),
+        )
+    }
+
+    fn picture(&self) -> Picture {
+        Picture::from_grid(&self.grid, |&lit| if lit { Rgb::WHITE } else { Rgb::BLACK })
+    }
+
+    fn count_lit(&self) -> usize {
+        self.grid.iter().filter(|b| **b).count()
+    }
+
+    fn enhance(&self, table: &[bool]) -> Image {
+        let new_default_value = if self.default_value() {
+            table[0x1ff]
+        } else {
+            table[0]
+        };
+
+        let grid = Grid::from_fn(self.grid.width() + 2, self.grid.height() + 2, |(x, y)| {
+            table[self.convolved((x as i64 - 1, y as i64 - 1))]
+        });
+        Image {
+            grid: grid.with_default(new_default_value),
+        }
+    }
+}
+
+fn number(it: &mut dyn Iterator<Item = bool>, n: usize) -> usize {
+    it.zip(0..n)
+        .map(|(b, i)| if b { 1 << (n - 1 - i) } else { 0 })
+        .fold(0, |acc, n| acc | n)
+}
+
+/// The enhancement table has an output pixel for each of the 2^9 neighbourhoods of a pixel.
+const TABLE_LEN: usize = 1 << Image::OFFSETS.len();
+
+/// A pixel of the scan, `#` for light and `.` for dark.
+fn pixel<'a>() -> impl Parser<'a, bool> {
+    either(map(tag("#"), |_| true), map(tag("."), |_| false))
+}
+
+/// The enhancement table: a line of exactly [`TABLE_LEN`] pixels.
+fn table(line: &str) -> Result<Vec<bool>, ParseError> {
+    let table = complete(many1(pixel()), line)?;
+    if table.len() != TABLE_LEN {
+        let found = match line.chars().nth(TABLE_LEN) {
+            Some(ch) => format!("{:?}", ch),
+            None => "the end of the line".to_string(),
+        };
+        return Err(ParseError {
+            column: table.len().min(TABLE_LEN) + 1,
+            expected: format!("{} pixels", TABLE_LEN),
+            found,
+        });
+    }
+    Ok(table)
+}
+
+/// The enhancement table and the image, as scanned before any steps.
+#[derive(Debug, Clone)]
+pub struct Scan {
+    table: Vec<bool>,
+    image: Image,
+}
+
+impl Scan {
+    fn enhanced(&self, passes: usize) -> Image {
+        let mut driver = Driver::new(self.clone());
+        driver.run(passes);
+        driver.into_sim().image
+    }
+}
+
+/// A step enhances the image once.
+impl Simulation for Scan {
+    type Outcome = ();
+
+    fn step(&mut self) {
+        self.image = self.image.enhance(&self.table);
+    }
+}
+
+pub struct Day20;
+
+impl Solution for Day20 {
+    const DAY: u32 = 20;
+    type Input = Scan;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Scan> {
+        let mut sections = parse::sections(input);
+        let (line, table_section) = sections
+            .next()
+            .ok_or_else(|| anyhow!("expected an enhancement table"))?;
+        let (first, image) = sections
+            .next()
+            .ok_or_else(|| anyhow!("expected an image after a blank line"))?;
+
+        let table = table(table_section).map_err(|error| LineError { line, error })?;
+        let image = Image {
+            grid: image
+                .parse::<Grid<bool>>()
+                .with_context(|| format!("in the image starting on line {}", first))?
+                .with_default(false),
+        };
+
+        Ok(Scan { table, image })
+    }
+
+    fn part1(scan: &Scan) -> usize {
+        //not 5294
+        scan.enhanced(2).count_lit()
+    }
+
+    fn part2(scan: &Scan) -> usize {
+        scan.enhanced(50).count_lit()
+    }
+}
+
+/// The image through the 50 enhancements of part 2, a frame a pass.
+pub fn draw(scan: &Scan) -> Vec<Picture> {
+    let mut frames = vec![scan.image.picture()];
+    Driver::new(scan.clone())
+        .observe(|_, scan, _| frames.push(scan.image.picture()))
+        .run(50);
+    frames
+}
+
+#[cfg(test)]
+mod test {
+    use crate::*;
+
+    #[test]
+    fn tables_are_checked() {
+        let image = "\n\n#.\n.#\n";
+        let full = "#.".repeat(256);
+        let scan = Day20::parse(&format!("{}{}", full, image)).unwrap();
+        assert_eq!(512, scan.table.len());
+        assert!(scan.table[0] && !scan.table[511]);
+
+        let err = Day20::parse(&format!("{}{}", &full[1..], image)).unwrap_err();
+        assert_eq!(
+            "line 1, column 512: expected 512 pixels, found the end of the line",
+            err.to_string()
+        );
+        let err = Day20::parse(&format!("{}#{}", full, image)).unwrap_err();
+        assert_eq!(
+            "line 1, column 513: expected 512 pixels, found '#'",
+            err.to_string()
+        );
+        let err = Day20::parse(&format!("{}x{}", &full[..10], image)).unwrap_err();
+        assert_eq!(
+            "line 1, column 11: expected the end of the line, found \"x\"",
+            err.to_string()
+        );
+    }
+}
@@ -1,104 +1,45 @@
+use anyhow::Result;
+use aoc::parse::{complete, either, map, pair, preceded, tag, xyz_ranges, ParseError};
+use aoc::Solution;
 use std::collections::HashSet;
 use std::ops::RangeInclusive;
+use std::str::FromStr;
+use vectory::{Aabb, IntVector};
 
 #[derive(Debug, Clone, Eq, PartialEq, Hash)]
 pub struct Cuboid {
-    pub x: RangeInclusive<i32>,
-    pub y: RangeInclusive<i32>,
-    pub z: RangeInclusive<i32>,
+    pub bounds: Aabb<3, i32>,
 }
 
 impl Cuboid {
-    pub fn part1(&self) -> bool {
-        let bounds = -50..=50;
-        bounds.contains(self.x.start())
-            && bounds.contains(self.x.end())
-            && bounds.contains(self.y.start())
-            && bounds.contains(self.y.end())
-            && bounds.contains(self.z.start())
-            && bounds.contains(self.z.end())
-    }
-
-    pub fn iter<'a>(&'a self) -> impl Iterator<Item = (i32, i32, i32)> + 'a {
-        self.x.clone().flat_map(move |x| {
-            self.y
-                .clone()
-                .flat_map(move |y| self.z.clone().map(move |z| (x, y, z)))
-        })
-    }
-
-    fn range_intersection(
-        left: &RangeInclusive<i32>,
-        right: &RangeInclusive<i32>,
-    ) -> Option<RangeInclusive<i32>> {
-        if left.contains(right.start()) || left.contains(right.end()) {
-            Some(*left.start().max(right.start())..=(*left.end().min(right.end())))
-        } else if right.contains(left.start()) || right.contains(left.end()) {
-            Some(*right.start().max(left.start())..=(*right.end().min(left.end())))
-        } else {
-            None
+    pub fn new(x: RangeInclusive<i32>, y: RangeInclusive<i32>, z: RangeInclusive<i32>) -> Cuboid {
+        Cuboid {
+            bounds: Aabb::new(
+                IntVector::from([*x.start(), *y.start(), *z.start()]),
+                IntVector::from([*x.end(), *y.end(), *z.end()]),
+            ),
         }
     }
 
-    fn range_sub(
-        left: &RangeInclusive<i32>,
-        right: &RangeInclusive<i32>,
-    ) -> Vec<RangeInclusive<i32>> {
-        let mut ret = Vec::new();
-        // Left side remainder
-        if left.contains(right.start()) && left.start() < right.start() {
-            ret.push(RangeInclusive::new(*left.start(), right.start() - 1));
-        }
-        // Right side remainder
-        if left.contains(right.end()) && left.end() > right.end() {
-            ret.push(RangeInclusive::new(right.end() + 1, *left.end()))
-        }
-        ret
+    pub fn part1(&self) -> bool {
+        let bounds = Aabb::new(IntVector::from([-50; 3]), IntVector::from([50; 3]));
+        bounds.contains_box(&self.bounds)
     }
 
-    fn intersection(&self, other: &Self) -> Option<Cuboid> {
-        let x = Cuboid::range_intersection(&self.x, &other.x);
-        let y = Cuboid::range_intersection(&self.y, &other.y);
-        let z = Cuboid::range_intersection(&self.z, &other.z);
-
-        x.zip(y).zip(z).map(|((x, y), z)| Cuboid { x, y, z })
+    pub fn iter(&self) -> impl Iterator<Item = IntVector<3, i32>> {
+        self.bounds.iter()
     }
 
     fn sub(&self, other: &Self) -> Vec<Cuboid> {
-        if let Some(isect) = Cuboid::intersection(self, other) {
-            let x_parts = Cuboid::range_sub(&self.x, &other.x);
-            let mut ret: Vec<Cuboid> = x_parts
-                .into_iter()
-                .map(|x| Cuboid {
-                    x,
-                    y: self.y.clone(),
-                    z: self.z.clone(),
-                })
-                .collect();
-            let y_parts = Cuboid::range_sub(&self.y, &other.y);
-            ret.extend(y_parts.into_iter().map(|y| Cuboid {
-                x: isect.x.clone(),
-                y,
-                z: self.z.clone(),
-            }));
-            let z_parts = Cuboid::range_sub(&self.z, &other.z);
-            ret.extend(z_parts.into_iter().map(|z| Cuboid {
-                x: isect.x.clone(),
-                y: isect.y.clone(),
-                z,
-            }));
-
-            ret
-        } else {
-            vec![self.clone()]
-        }
+        self.bounds
+            .subtract(&other.bounds)
+            .into_iter()
+            .map(|bounds| Cuboid { bounds })
+            .collect()
     }
 
     fn volume(&self) -> u64 {
-        let mut vol: u64 = (self.x.end() - self.x.start()) as u64 + 1;
-        vol *= (self.y.end() - self.y.start()) as u64 + 1;
-        vol *= (self.z.end() - self.z.start()) as u64 + 1;
-        vol
+        self.bounds.extent().iter().map(|&e| e as u64).product()
     }
 }
 
@@ -114,15 +55,13 @@ impl VolSet {
             VolSet::Simple(left) => {
                 let mut cubes = left.sub(&other);
                 cubes.push(other);
-                let ret = VolSet::Union(HashSet::from_iter(cubes.into_iter()));
-                ret
+                VolSet::Union(HashSet::from_iter(cubes))
             }
             VolSet::Union(left) => {
                 let mut cubes: Vec<Cuboid> = left.iter().flat_map(|c| c.sub(&other)).collect();
                 cubes.push(other);
 
-                let ret = VolSet::Union(HashSet::from_iter(cubes.into_iter()));
-                ret
+                VolSet::Union(HashSet::from_iter(cubes))
             }
         }
     }
@@ -134,7 +73,7 @@ impl VolSet {
                 if cubes.len() == 1 {
                     VolSet::Simple(cubes.remove(0))
                 } else {
-                    VolSet::Union(HashSet::from_iter(cubes.into_iter()))
+                    VolSet::Union(HashSet::from_iter(cubes))
                 }
             }
             VolSet::Union(left) => {
@@ -152,55 +91,77 @@ impl VolSet {
     }
 }
 
+#[derive(Debug, Clone)]
+pub struct Step {
+    on: bool,
+    vol: Cuboid,
+}
+
+impl FromStr for Step {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let switch = either(map(tag("on"), |_| true), map(tag("off"), |_| false));
+        let (on, [x, y, z]) = complete(pair(switch, preceded(tag(" "), xyz_ranges())), s)?;
+        Ok(Step {
+            on,
+            vol: Cuboid::new(x, y, z),
+        })
+    }
+}
+
+fn build_volume(steps: &[Step]) -> VolSet {
+    let mut vol = VolSet::Union(HashSet::new());
+    for step in steps {
+        if step.on {
+            vol = vol.union(step.vol.clone());
+        } else {
+            vol = vol.subtract(step.vol.clone());
+        }
+    }
+    vol
+}
+
+pub struct Day22;
+
+impl Solution for Day22 {
+    const DAY: u32 = 22;
+    type Input = Vec<Step>;
+    type Part1 = u64;
+    type Part2 = u64;
+
+    fn parse(input: &str) -> Result<Vec<Step>> {
+        Ok(aoc::parse_lines(input)?)
+    }
+
+    fn part1(input: &Vec<Step>) -> u64 {
+        let part1_steps: Vec<_> = input.iter().filter(|s| s.vol.part1()).cloned().collect();
+        build_volume(&part1_steps).volume()
+    }
+
+    fn part2(input: &Vec<Step>) -> u64 {
+        build_volume(input).volume()
+    }
+}
+
 #[cfg(test)]
 mod test {
     use crate::*;
+    use proptest::prelude::*;
 
     #[test]
     fn test_sub_center() {
-        let left = Cuboid {
-            x: -1..=1,
-            y: -1..=1,
-            z: -1..=1,
-        };
-
-        let right = Cuboid {
-            x: 0..=0,
-            y: 0..=0,
-            z: 0..=0,
-        };
+        let left = Cuboid::new(-1..=1, -1..=1, -1..=1);
+
+        let right = Cuboid::new(0..=0, 0..=0, 0..=0);
 
         let expected: Vec<Cuboid> = vec![
-            Cuboid {
-                x: -1..=-1,
-                y: -1..=1,
-                z: -1..=1,
-            },
-            Cuboid {
-                x: 1..=1,
-                y: -1..=1,
-                z: -1..=1,
-            },
-            Cuboid {
-                x: 0..=0,
-                y: -1..=-1,
-                z: -1..=1,
-            },
-            Cuboid {
-                x: 0..=0,
-                y: 1..=1,
-                z: -1..=1,
-            },
-            Cuboid {
-                x: 0..=0,
-                y: 0..=0,
-                z: -1..=-1,
-            },
-            Cuboid {
-                x: 0..=0,
-                y: 0..=0,
-                z: 1..=1,
-            },
+            Cuboid::new(-1..=-1, -1..=1, -1..=1),
+            Cuboid::new(1..=1, -1..=1, -1..=1),
+            Cuboid::new(0..=0, -1..=-1, -1..=1),
+            Cuboid::new(0..=0, 1..=1, -1..=1),
+            Cuboid::new(0..=0, 0..=0, -1..=-1),
+            Cuboid::new(0..=0, 0..=0, 1..=1),
         ];
 
         let result = left.sub(&right);
@@ -209,34 +170,14 @@ mod test {
 
     #[test]
     fn test_sub_corner() {
-        let left = Cuboid {
-            x: -1..=1,
-            y: -1..=1,
-            z: -1..=1,
-        };
-
-        let right = Cuboid {
-            x: 1..=2,
-            y: 1..=2,
-            z: 1..=2,
-        };
+        let left = Cuboid::new(-1..=1, -1..=1, -1..=1);
+
+        let right = Cuboid::new(1..=2, 1..=2, 1..=2);
 
         let expected: Vec<Cuboid> = vec![
-            Cuboid {
-                x: -1..=0,
-                y: -1..=1,
-                z: -1..=1,
-            },
-            Cuboid {
-                x: 1..=1,
-                y: -1..=0,
-                z: -1..=1,
-            },
-            Cuboid {
-                x: 1..=1,
-                y: 1..=1,
-                z: -1..=0,
-            },
+            Cuboid::new(-1..=0, -1..=1, -1..=1),
+            Cuboid::new(1..=1, -1..=0, -1..=1),
+            Cuboid::new(1..=1, 1..=1, -1..=0),
         ];
 
         let result = left.sub(&right);
@@ -245,21 +186,81 @@ mod test {
 
     #[test]
     fn test_sub_separate() {
-        let left = Cuboid {
-            x: -1..=1,
-            y: -1..=1,
-            z: -1..=1,
-        };
-
-        let right = Cuboid {
-            x: 2..=3,
-            y: 2..=3,
-            z: 2..=3,
-        };
+        let left = Cuboid::new(-1..=1, -1..=1, -1..=1);
+
+        let right = Cuboid::new(2..=3, 2..=3, 2..=3);
 
         let expected: Vec<Cuboid> = vec![left.clone()];
 
         let result = left.sub(&right);
         assert_eq!(expected, result);
     }
+
+    #[test]
+    fn reboots_start_dark() {
+        let steps = Day22::parse("off x=0..1,y=0..1,z=0..1\non x=60..60,y=0..0,z=0..0").unwrap();
+        assert_eq!(0, Day22::part1(&steps));
+        assert_eq!(1, Day22::part2(&steps));
+        assert_eq!(0, Day22::part2(&Day22::parse("").unwrap()));
+    }
+
+    /// Cuboids near the origin, small enough to count their cubes one at a time.
+    fn cuboid() -> impl Strategy<Value = Cuboid> {
+        prop::array::uniform3((-6i32..=6, 0i32..=5))
+            .prop_map(|[x, y, z]| Cuboid::new(x.0..=x.0 + x.1, y.0..=y.0 + y.1, z.0..=z.0 + z.1))
+    }
+
+    fn overlap(a: &Cuboid, b: &Cuboid) -> u64 {
+        a.bounds
+            .intersection(&b.bounds)
+            .map_or(0, |bounds| Cuboid { bounds }.volume())
+    }
+
+    proptest! {
+        #[test]
+        fn subtraction_leaves_disjoint_pieces(a in cuboid(), b in cuboid()) {
+            let pieces = a.sub(&b);
+            prop_assert_eq!(
+                a.volume(),
+                pieces.iter().map(Cuboid::volume).sum::<u64>() + overlap(&a, &b)
+            );
+            for (i, piece) in pieces.iter().enumerate() {
+                prop_assert!(a.bounds.contains_box(&piece.bounds));
+                prop_assert_eq!(0, overlap(piece, &b));
+                for other in &pieces[i + 1..] {
+                    prop_assert_eq!(0, overlap(piece, other));
+                }
+            }
+        }
+
+        #[test]
+        fn unions_count_overlaps_once(a in cuboid(), b in cuboid()) {
+            let union = VolSet::Simple(a.clone()).union(b.clone());
+            prop_assert_eq!(a.volume() + b.volume() - overlap(&a, &b), union.volume());
+        }
+
+        #[test]
+        fn differences_lose_the_overlap(a in cuboid(), b in cuboid()) {
+            let difference = VolSet::Simple(a.clone()).subtract(b.clone());
+            prop_assert_eq!(a.volume() - overlap(&a, &b), difference.volume());
+        }
+
+        #[test]
+        fn steps_light_the_cubes_they_say(
+            steps in prop::collection::vec((any::<bool>(), cuboid()), 0..8)
+        ) {
+            let steps: Vec<Step> = steps.into_iter().map(|(on, vol)| Step { on, vol }).collect();
+            let mut lit = HashSet::new();
+            for step in &steps {
+                if step.on {
+                    lit.extend(step.vol.iter());
+                } else {
+                    for cube in step.vol.iter() {
+                        lit.remove(&cube);
+                    }
+                }
+            }
+            prop_assert_eq!(lit.len() as u64, build_volume(&steps).volume());
+        }
+    }
 }
@@ -1,12 +1,14 @@
+use anyhow::Result;
+use aoc::Solution;
 use itertools::Itertools;
-use std::cmp::Ordering;
-use std::collections::{BTreeMap, BinaryHeap, HashMap, HashSet};
+use std::collections::{BTreeMap, HashMap};
 use std::fmt::{Display, Formatter};
 use std::hash::Hash;
 use std::iter::once;
 use std::ops::Index;
 use std::rc::Rc;
 use std::str::FromStr;
+use thiserror::Error;
 
 #[derive(Debug, Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
 pub enum Color {
@@ -27,14 +29,16 @@ impl Color {
     }
 }
 
-impl From<char> for Color {
-    fn from(ch: char) -> Self {
+impl TryFrom<char> for Color {
+    type Error = UnknownTile;
+
+    fn try_from(ch: char) -> Result<Self, Self::Error> {
         match ch {
-            'A' => Color::A,
-            'B' => Color::B,
-            'C' => Color::C,
-            'D' => Color::D,
-            ch => panic!("unsupported color {}", ch),
+            'A' => Ok(Color::A),
+            'B' => Ok(Color::B),
+            'C' => Ok(Color::C),
+            'D' => Ok(Color::D),
+            ch => Err(UnknownTile(ch)),
         }
     }
 }
@@ -70,14 +74,21 @@ pub enum Tile {
     HALL,
 }
 
-impl From<char> for Tile {
-    fn from(ch: char) -> Self {
+/// A character that's neither part of a burrow nor an amphipod.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Error)]
+#[error("expected a wall, an open space or an amphipod A to D, found {0:?}")]
+pub struct UnknownTile(pub char);
+
+impl TryFrom<char> for Tile {
+    type Error = UnknownTile;
+
+    fn try_from(ch: char) -> Result<Self, Self::Error> {
         match ch {
-            '#' => Tile::WALL,
-            '.' => Tile::HALL,
-            ch if ch.is_uppercase() => Tile::HOME(ch.into()),
-            ' ' => Tile::WALL,
-            _ => panic!("unexpected char {}", ch),
+            '#' => Ok(Tile::WALL),
+            '.' => Ok(Tile::HALL),
+            ch if ch.is_uppercase() => Ok(Tile::HOME(ch.try_into()?)),
+            ' ' => Ok(Tile::WALL),
+            ch => Err(UnknownTile(ch)),
         }
     }
 }
@@ -98,14 +109,47 @@ pub struct Maze {
     grid: Vec<Vec<Tile>>,
 }
 
+/// Why a burrow diagram doesn't parse. Lines and columns count from 1.
+#[derive(Clone, Debug, Eq, PartialEq, Error)]
+pub enum ParseBurrowError {
+    #[error("line {line}, column {column}: {error}")]
+    Tile {
+        line: usize,
+        column: usize,
+        error: UnknownTile,
+    },
+    #[error("expected a hallway, rooms and the walls around them, found {lines} lines")]
+    Short { lines: usize },
+    #[error("line {line}: expected an amphipod in each of the four rooms, found {count}")]
+    Rooms { line: usize, count: usize },
+    #[error("expected {rooms} amphipods of each type, one for each row of rooms, found {count} of type {color:?}")]
+    Amphipods {
+        color: Color,
+        count: usize,
+        rooms: usize,
+    },
+}
+
 impl FromStr for Maze {
-    type Err = anyhow::Error;
+    type Err = ParseBurrowError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         let grid = s
             .lines()
-            .map(|l| l.chars().map(|ch| ch.into()).collect())
-            .collect();
+            .enumerate()
+            .map(|(y, l)| {
+                l.chars()
+                    .enumerate()
+                    .map(|(x, ch)| {
+                        Tile::try_from(ch).map_err(|error| ParseBurrowError::Tile {
+                            line: y + 1,
+                            column: x + 1,
+                            error,
+                        })
+                    })
+                    .collect()
+            })
+            .collect::<Result<_, _>>()?;
         Ok(Maze { grid })
     }
 }
@@ -176,37 +220,6 @@ impl Index<&(usize, usize)> for Maze {
     }
 }
 
-#[derive(Copy, Clone, Eq, PartialEq)]
-struct VisitState<Node>
-where
-    Node: Eq + PartialEq + Clone,
-{
-    dist: u32,
-    n: Node,
-}
-
-impl<Node> Ord for VisitState<Node>
-where
-    Node: Ord + Eq + PartialEq + Clone,
-{
-    fn cmp(&self, other: &Self) -> Ordering {
-        other
-            .dist
-            .cmp(&self.dist)
-            .then_with(|| self.n.cmp(&other.n))
-    }
-}
-
-// `PartialOrd` needs to be implemented as well.
-impl<Node> PartialOrd for VisitState<Node>
-where
-    Node: Ord + Eq + PartialEq + Clone,
-{
-    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
-        Some(self.cmp(other))
-    }
-}
-
 #[derive(Debug, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
 pub struct World {
     maze: Rc<Maze>,
@@ -278,7 +291,7 @@ impl World {
         assert!(self.maze.is_open(to));
         if let Some(p) = self.pawns.remove(from) {
             if !self.pawns.contains_key(to) {
-                self.pawns.insert(to.clone(), p);
+                self.pawns.insert(*to, p);
             } else {
                 panic!("move to occupied space at to {:?}", to)
             }
@@ -288,12 +301,17 @@ impl World {
     }
 
     pub fn path_dist(&self, start: &(usize, usize)) -> HashMap<(usize, usize), u32> {
-        dijkstra(start, |pos| {
-            self.maze
-                .neighbors(pos)
-                .filter(|n| self.is_vacant(n))
-                .map(|n| (n, 1))
-        })
+        search::dijkstra(
+            *start,
+            |pos| {
+                self.maze
+                    .neighbors(pos)
+                    .filter(|n| self.is_vacant(n))
+                    .map(|n| (n, 1))
+            },
+            |_| false,
+        )
+        .dist
     }
 
     pub fn is_settled(&self) -> bool {
@@ -341,51 +359,252 @@ impl Display for World {
                     write!(f, "{}", tile).unwrap();
                 }
             }
-            write!(f, "\n").unwrap();
+            writeln!(f).unwrap();
         }
         Ok(())
     }
 }
 
-pub fn dijkstra<Node, It: Iterator<Item = (Node, u32)>, NF: Fn(&Node) -> It>(
-    start: &Node,
-    neighbors: NF,
-) -> HashMap<Node, u32>
-where
-    Node: Ord + Eq + PartialEq + Clone + Hash,
-{
-    let mut distance = HashMap::new();
-    let mut visited = HashSet::new();
-    // let mut prev = HashMap::new();
-
-    distance.insert(start.clone(), 0);
-
-    let mut heap = BinaryHeap::new();
-    heap.push(VisitState {
-        dist: 0,
-        n: start.clone(),
-    });
-
-    while let Some(VisitState { dist, n: current }) = heap.pop() {
-        let current_cost = distance[&current];
-        if dist > current_cost || visited.contains(&current) {
-            continue;
-        }
+/// A burrow with the amphipods where they start, and the rooms they're each to end up in.
+#[derive(Debug, Clone)]
+pub struct Burrow {
+    maze: Maze,
+    pawns: Vec<Pawn>,
+}
 
-        if current_cost == u32::MAX {
-            // Disconnected; some obstructions exist
-            break;
+impl Burrow {
+    /// Reads a burrow from its diagram, checking there's an amphipod for every place in the rooms.
+    fn new(diagram: &[&str]) -> Result<Burrow, ParseBurrowError> {
+        if diagram.len() < 4 {
+            return Err(ParseBurrowError::Short {
+                lines: diagram.len(),
+            });
+        }
+        let start: Maze = diagram.join("\n").parse()?;
+        let pawns: Vec<Pawn> = start.pawns().collect();
+
+        let mut rooms = 0;
+        for (y, row) in &pawns.iter().group_by(|p| p.start.1) {
+            let count = row.count();
+            if count != 4 {
+                return Err(ParseBurrowError::Rooms { line: y + 1, count });
+            }
+            rooms += 1;
         }
-        for (n, cost) in neighbors(&current).filter(|(n, _cost)| !visited.contains(n)) {
-            let d = distance.entry(n.clone()).or_insert(u32::MAX);
-            if *d > current_cost + cost {
-                *d = current_cost + cost;
-                // prev.insert(n, current);
-                heap.push(VisitState { dist: *d, n })
+        let counts = pawns.iter().counts_by(|p| p.color);
+        for color in [Color::A, Color::B, Color::C, Color::D] {
+            let count = counts.get(&color).copied().unwrap_or(0);
+            if count != rooms {
+                return Err(ParseBurrowError::Amphipods {
+                    color,
+                    count,
+                    rooms,
+                });
             }
         }
-        visited.insert(current);
+
+        // Every room is for one type of amphipod, A to D from left to right
+        let grid = start
+            .grid
+            .iter()
+            .map(|row| {
+                let mut homes = [Color::A, Color::B, Color::C, Color::D].into_iter();
+                row.iter()
+                    .map(|&tile| match tile {
+                        Tile::HOME(_) => Tile::HOME(homes.next().unwrap_or(Color::D)),
+                        tile => tile,
+                    })
+                    .collect()
+            })
+            .collect();
+        Ok(Burrow {
+            maze: Maze { grid },
+            pawns,
+        })
+    }
+
+    fn least_energy(&self) -> u32 {
+        // Amphipods can always get past each other through the hallway, so every burrow that
+        // parses has a way to sort them
+        solve(World::new(self.maze.clone(), self.pawns.clone())).expect("Failed to solve")
+    }
+}
+
+/// The burrow as first seen, and unfolded to its full size with two more rows in every room.
+#[derive(Debug, Clone)]
+pub struct Burrows {
+    folded: Burrow,
+    unfolded: Burrow,
+}
+
+pub struct Day23;
+
+impl Solution for Day23 {
+    const DAY: u32 = 23;
+    type Input = Burrows;
+    type Part1 = u32;
+    type Part2 = u32;
+
+    fn parse(input: &str) -> Result<Burrows> {
+        let mut diagram: Vec<&str> = input.lines().collect();
+        let folded = Burrow::new(&diagram)?;
+        diagram.splice(3..3, ["  #D#C#B#A#", "  #D#B#A#C#"]);
+        let unfolded = Burrow::new(&diagram)?;
+        Ok(Burrows { folded, unfolded })
     }
 
-    distance
+    fn part1(burrows: &Burrows) -> u32 {
+        burrows.folded.least_energy()
+    }
+
+    fn part2(burrows: &Burrows) -> u32 {
+        burrows.unfolded.least_energy()
+    }
+}
+
+fn solve(world: World) -> Option<u32> {
+    let search = search::dijkstra(world, neighbors, World::is_settled);
+    search.goal_cost()
+}
+
+fn neighbors(world: &World) -> impl Iterator<Item = (World, u32)> {
+    //println!("{}\n", &world);
+    //if there are move-home moves, do those
+    let ready_homes: HashMap<Color, (usize, usize)> = world
+        .find_ready_homes()
+        .map(|h| {
+            if let Tile::HOME(color) = world.tile(&h) {
+                (color, h)
+            } else {
+                unreachable!()
+            }
+        })
+        .fold(HashMap::new(), |mut acc, (color, h)| {
+            // pick the furthest-in spot in the room
+            if let Some(pos) = acc.get(&color) {
+                if pos.1 < h.1 {
+                    acc.insert(color, h);
+                }
+            } else {
+                acc.insert(color, h);
+            }
+            acc
+        });
+    let move_homes: Vec<_> = if ready_homes.is_empty() {
+        Vec::new()
+    } else {
+        world
+            .pawns()
+            .iter()
+            .filter_map(move |(pawn_pos, pawn)| {
+                if let Tile::HALL = world.tile(pawn_pos) {
+                    if let Some(ready_home_pos) = ready_homes.get(&pawn.color) {
+                        let dist = world.path_dist(pawn_pos);
+                        if let Some(distance) = dist.get(ready_home_pos) {
+                            let cost = distance * pawn.color.step_cost();
+                            Some((*pawn_pos, *ready_home_pos, cost))
+                        } else {
+                            None
+                        }
+                    } else {
+                        None
+                    }
+                } else {
+                    None
+                }
+            })
+            .collect()
+    };
+
+    if !move_homes.is_empty() {
+        move_homes
+            .iter()
+            .map(move |(from, to, dist)| {
+                let mut w = world.clone();
+                w.do_move(from, to);
+                (w, *dist)
+            })
+            .collect::<Vec<_>>()
+            .into_iter()
+    } else {
+        //no move-homes, do a move-out
+
+        //find unsettled pawns
+        let move_outs: Vec<_> = world
+            .pawns()
+            .iter()
+            .filter(|(pawn_pos, _)| {
+                if let Tile::HOME(_) = world.tile(pawn_pos) {
+                    !world.is_pawn_settled_at(pawn_pos)
+                } else {
+                    false
+                }
+            })
+            .flat_map(|(pawn_pos, pawn)| {
+                let dist = world.path_dist(pawn_pos);
+                dist.into_iter()
+                    .filter(|(pos, _)| {
+                        matches!(world.tile(pos), Tile::HALL) && !world.blocks_doorway(pos)
+                    })
+                    .map(|(dest, distance)| {
+                        let cost = distance * pawn.color.step_cost();
+                        (*pawn_pos, dest, cost)
+                    })
+            })
+            .collect();
+        move_outs
+            .iter()
+            .map(move |(from, to, dist)| {
+                let mut w = world.clone();
+                w.do_move(from, to);
+                (w, *dist)
+            })
+            .collect::<Vec<_>>()
+            .into_iter()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::*;
+
+    const EXAMPLE: &str = "#############\n#...........#\n###B#C#B#D###\n  #A#D#C#A#\n  #########";
+
+    fn error(input: &str) -> ParseBurrowError {
+        Day23::parse(input)
+            .unwrap_err()
+            .downcast::<ParseBurrowError>()
+            .unwrap()
+    }
+
+    #[test]
+    fn parse_errors() {
+        assert_eq!(Ok(Color::C), 'C'.try_into());
+        assert_eq!(Err(UnknownTile('E')), Tile::try_from('E'));
+        assert_eq!(
+            ParseBurrowError::Tile {
+                line: 4,
+                column: 6,
+                error: UnknownTile('x')
+            },
+            error(&EXAMPLE.replace("D#C#A", "x#C#A"))
+        );
+        assert_eq!(
+            "line 3, column 4: expected a wall, an open space or an amphipod A to D, found 'E'",
+            error(&EXAMPLE.replace("B#C#B", "E#C#B")).to_string()
+        );
+        assert_eq!(
+            ParseBurrowError::Rooms { line: 4, count: 3 },
+            error(&EXAMPLE.replace("#A#D", "#.#D"))
+        );
+        assert_eq!(
+            ParseBurrowError::Amphipods {
+                color: Color::A,
+                count: 1,
+                rooms: 2
+            },
+            error(&EXAMPLE.replace("#D#C#A", "#D#C#B"))
+        );
+        assert_eq!(ParseBurrowError::Short { lines: 1 }, error("#####"));
+    }
 }
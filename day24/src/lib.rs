@@ -0,0 +1,349 @@
+use aoc::Solution;
+use std::collections::{HashMap, HashSet};
+use std::str::FromStr;
+
+use anyhow::Result;
+use itertools::{Either, Itertools};
+use thiserror::Error;
+
+#[derive(Debug, Copy, Clone, Hash, Eq, PartialEq)]
+pub enum Reg {
+    W,
+    X,
+    Y,
+    Z,
+}
+
+impl Reg {
+    fn parse(column: usize, token: &str) -> Result<Reg, ParseInstructionError> {
+        match token {
+            "w" => Ok(Reg::W),
+            "x" => Ok(Reg::X),
+            "y" => Ok(Reg::Y),
+            "z" => Ok(Reg::Z),
+            _ => Err(ParseInstructionError::Register {
+                column,
+                token: token.to_string(),
+            }),
+        }
+    }
+}
+
+/// The right-hand side of an instruction: a number or a register.
+pub type Operand = Either<i64, Reg>;
+
+#[derive(Debug, Eq, PartialEq, Hash)]
+pub enum Instruction {
+    INP(Reg),
+    ADD(Reg, Operand),
+    MUL(Reg, Operand),
+    DIV(Reg, Operand),
+    MOD(Reg, Operand),
+    EQL(Reg, Operand),
+}
+
+/// Why a line isn't an instruction. Columns count from 1.
+#[derive(Clone, Debug, Eq, PartialEq, Error)]
+pub enum ParseInstructionError {
+    #[error("column {column}: unknown instruction {token:?}")]
+    Opcode { column: usize, token: String },
+    #[error("column {column}: expected a register, found {token:?}")]
+    Register { column: usize, token: String },
+    #[error("column {column}: expected a register or a number, found {token:?}")]
+    Operand { column: usize, token: String },
+    #[error("column {column}: expected {expected}")]
+    Missing {
+        column: usize,
+        expected: &'static str,
+    },
+    #[error("column {column}: unexpected {token:?} after the operands")]
+    Trailing { column: usize, token: String },
+}
+
+impl Instruction {
+    fn parse_right(column: usize, s: &str) -> Result<Operand, ParseInstructionError> {
+        if s.starts_with(|ch: char| ch.is_lowercase()) {
+            Reg::parse(column, s).map(Either::Right)
+        } else {
+            s.parse()
+                .map(Either::Left)
+                .map_err(|_| ParseInstructionError::Operand {
+                    column,
+                    token: s.to_string(),
+                })
+        }
+    }
+}
+
+impl FromStr for Instruction {
+    type Err = ParseInstructionError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let missing = |expected| ParseInstructionError::Missing {
+            column: aoc::end_column(s),
+            expected,
+        };
+        let mut words = aoc::words(s);
+        let (op_column, op) = words.next().ok_or(missing("an instruction"))?;
+        let binary: Option<fn(Reg, Operand) -> Instruction> = match op {
+            "inp" => None,
+            "add" => Some(Instruction::ADD),
+            "mul" => Some(Instruction::MUL),
+            "div" => Some(Instruction::DIV),
+            "mod" => Some(Instruction::MOD),
+            "eql" => Some(Instruction::EQL),
+            _ => {
+                return Err(ParseInstructionError::Opcode {
+                    column: op_column,
+                    token: op.to_string(),
+                })
+            }
+        };
+        let (left_column, left) = words.next().ok_or(missing("a register"))?;
+        let left = Reg::parse(left_column, left)?;
+
+        let ins = match binary {
+            None => Instruction::INP(left),
+            Some(binary) => {
+                let (right_column, right) = words.next().ok_or(missing("a second operand"))?;
+                binary(left, Instruction::parse_right(right_column, right)?)
+            }
+        };
+        if let Some((column, token)) = words.next() {
+            return Err(ParseInstructionError::Trailing {
+                column,
+                token: token.to_string(),
+            });
+        }
+        Ok(ins)
+    }
+}
+
+struct Alu {
+    reg: HashMap<Reg, i64>,
+}
+
+impl Alu {
+    pub fn new() -> Alu {
+        Alu {
+            reg: HashMap::from([(Reg::W, 0), (Reg::X, 0), (Reg::Y, 0), (Reg::Z, 0)]),
+        }
+    }
+
+    pub fn set(&mut self, reg: Reg, n: i64) {
+        self.reg.insert(reg, n);
+    }
+
+    fn right_val(&self, operand: &Operand) -> i64 {
+        match operand {
+            Either::Left(n) => *n,
+            Either::Right(r) => self.reg[r],
+        }
+    }
+
+    fn exec<It: Iterator<Item = i64>>(&mut self, ins: &Instruction, input_it: &mut It) {
+        match ins {
+            Instruction::INP(left) => {
+                let val = input_it.next().unwrap();
+                self.reg.insert(*left, val);
+            }
+            Instruction::ADD(left, right) => {
+                let val_l = self.reg[left];
+                let val_r = self.right_val(right);
+                self.reg.insert(*left, val_l + val_r);
+            }
+            Instruction::MUL(left, right) => {
+                let val_l = self.reg[left];
+                let val_r = self.right_val(right);
+                self.reg.insert(*left, val_l * val_r);
+            }
+            Instruction::DIV(left, right) => {
+                let val_l = self.reg[left];
+                let val_r = self.right_val(right);
+                self.reg.insert(*left, val_l / val_r);
+            }
+            Instruction::MOD(left, right) => {
+                let val_l = self.reg[left];
+                let val_r = self.right_val(right);
+                self.reg.insert(*left, val_l % val_r);
+            }
+            Instruction::EQL(left, right) => {
+                let val_l = self.reg[left];
+                let val_r = self.right_val(right);
+                self.reg.insert(*left, if val_l == val_r { 1 } else { 0 });
+            }
+        }
+    }
+
+    pub fn run_program(&mut self, prog: &[Instruction], input: &[i64]) {
+        let mut inp_it = input.iter().copied();
+        for ins in prog {
+            self.exec(ins, &mut inp_it);
+        }
+    }
+
+    pub fn validate(&mut self, prog: &[Instruction], input: &[i64]) -> i64 {
+        self.run_program(prog, input);
+
+        self.reg[&Reg::Z]
+    }
+}
+
+/// How much `section` divides z by at most.
+fn divisor(section: &[Instruction]) -> i64 {
+    section
+        .iter()
+        .map(|ins| match ins {
+            Instruction::DIV(Reg::Z, Either::Left(n)) => n.abs().max(1),
+            _ => 1,
+        })
+        .fold(1, i64::saturating_mul)
+}
+
+/// A depth-first search of the MONAD sections for inputs that take z to 0, trying `digits` in
+/// order so that the first found is the largest or smallest.
+struct Search<'a> {
+    sections: Vec<&'a [Instruction]>,
+    /// How much the sections from each index on can divide z by, together.
+    shrink: Vec<i64>,
+    digits: Vec<i64>,
+    /// States already known not to lead to 0.
+    dead: HashSet<(usize, i64)>,
+}
+
+impl Search<'_> {
+    /// Pushes the inputs that take `z` to 0 from section `i` onto `inputs`, if any do.
+    ///
+    /// Each section shrinks z only by dividing it, so a z at least as big as everything still to
+    /// come can divide it by can't reach 0, and needn't be searched.
+    fn inputs(&mut self, i: usize, z: i64, inputs: &mut Vec<i64>) -> bool {
+        if i == self.sections.len() {
+            return z == 0;
+        }
+        if z >= self.shrink[i] || self.dead.contains(&(i, z)) {
+            return false;
+        }
+        for w in self.digits.clone() {
+            let mut alu = Alu::new();
+            alu.set(Reg::Z, z);
+            alu.set(Reg::W, w);
+            inputs.push(w);
+            if self.inputs(i + 1, alu.validate(self.sections[i], &[w]), inputs) {
+                return true;
+            }
+            inputs.pop();
+        }
+        self.dead.insert((i, z));
+        false
+    }
+}
+
+/// The largest or smallest model number the MONAD program accepts.
+fn model_number(program: &[Instruction], get_max: bool) -> String {
+    let sections: Vec<_> = program
+        .split(|ins| *ins == Instruction::INP(Reg::W))
+        .filter(|s| !s.is_empty())
+        .collect();
+    let mut shrink = vec![1i64; sections.len() + 1];
+    for (i, section) in sections.iter().enumerate().rev() {
+        shrink[i] = shrink[i + 1].saturating_mul(divisor(section));
+    }
+    let digits = if get_max {
+        (1..=9).rev().collect()
+    } else {
+        (1..=9).collect()
+    };
+
+    let mut search = Search {
+        sections,
+        shrink,
+        digits,
+        dead: HashSet::new(),
+    };
+    let mut inputs = Vec::new();
+    assert!(search.inputs(0, 0, &mut inputs), "no model number is valid");
+    aoc::note("states", search.dead.len());
+
+    //test it
+    let mut alu = Alu::new();
+    assert_eq!(0, alu.validate(program, &inputs));
+
+    inputs.iter().map(|i| i.to_string()).join("")
+}
+
+pub struct Day24;
+
+impl Solution for Day24 {
+    const DAY: u32 = 24;
+    type Input = Vec<Instruction>;
+    type Part1 = String;
+    type Part2 = String;
+
+    fn parse(input: &str) -> Result<Vec<Instruction>> {
+        Ok(aoc::parse_lines(input)?)
+    }
+
+    fn part1(program: &Vec<Instruction>) -> String {
+        model_number(program, true)
+    }
+
+    fn part2(program: &Vec<Instruction>) -> String {
+        model_number(program, false)
+    }
+}
+
+//Notes: w is only written for input
+//x and y are cleared before use in each section
+//z is the only register that survives across inputs.
+
+#[cfg(test)]
+mod test {
+    use crate::*;
+
+    #[test]
+    fn parse_errors() {
+        assert_eq!(
+            Ok(Instruction::ADD(Reg::Z, Either::Left(-3))),
+            "add z -3".parse()
+        );
+        assert_eq!(
+            Ok(Instruction::EQL(Reg::X, Either::Right(Reg::W))),
+            "eql x w".parse()
+        );
+        assert_eq!(
+            Err(ParseInstructionError::Opcode {
+                column: 1,
+                token: "sub".to_string()
+            }),
+            "sub x 1".parse::<Instruction>()
+        );
+        assert_eq!(
+            Err(ParseInstructionError::Register {
+                column: 5,
+                token: "q".to_string()
+            }),
+            "mul q 0".parse::<Instruction>()
+        );
+        assert_eq!(
+            Err(ParseInstructionError::Operand {
+                column: 7,
+                token: "1.5".to_string()
+            }),
+            "div z 1.5".parse::<Instruction>()
+        );
+        assert_eq!(
+            Err(ParseInstructionError::Missing {
+                column: 6,
+                expected: "a second operand"
+            }),
+            "mod x".parse::<Instruction>()
+        );
+        assert_eq!(
+            Err(ParseInstructionError::Trailing {
+                column: 7,
+                token: "y".to_string()
+            }),
+            "inp w y".parse::<Instruction>()
+        );
+    }
+}
@@ -0,0 +1,107 @@
+use anyhow::Result;
+use aoc::Solution;
+use grid::Grid;
+use render::{Picture, Rgb};
+use sim::{Driver, Simulation};
+use std::str::FromStr;
+
+#[derive(Debug, Clone)]
+pub struct World {
+    grid: Grid<char>,
+}
+
+impl FromStr for World {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let grid = s.parse::<Grid<char>>()?.wrapping();
+        Ok(World { grid })
+    }
+}
+
+impl World {
+    fn next(&self, pos: &(usize, usize), direction: char) -> (usize, usize) {
+        let offset = if direction == '>' { (1, 0) } else { (0, 1) };
+        self.grid.offset(*pos, offset).unwrap()
+    }
+
+    /// The east-facing herd in red and the south-facing one in blue.
+    fn picture(&self) -> Picture {
+        Picture::from_grid(&self.grid, |&ch| match ch {
+            '>' => Rgb::RED,
+            'v' => Rgb::BLUE,
+            _ => Rgb::GREY,
+        })
+    }
+
+    fn points(&self) -> impl Iterator<Item = ((usize, usize), char)> + '_ {
+        self.grid.cells().map(|(p, &ch)| (p, ch))
+    }
+
+    fn do_move(&mut self, from: &(usize, usize), to: &(usize, usize)) {
+        self.grid[*to] = self.grid[*from];
+        self.grid[*from] = '.';
+    }
+
+    fn do_moves(&mut self, sources: &[((usize, usize), char)]) {
+        for (from, ch) in sources.iter() {
+            let to = self.next(from, *ch);
+            self.do_move(from, &to);
+        }
+    }
+}
+
+/// A step reports how many sea cucumbers moved.
+impl Simulation for World {
+    type Outcome = usize;
+
+    fn step(&mut self) -> usize {
+        //east first
+        let east_moves: Vec<_> = self
+            .points()
+            .filter(|(_, ch)| *ch == '>')
+            .filter(|(p, ch)| self.grid[self.next(p, *ch)] == '.')
+            .collect();
+        self.do_moves(&east_moves);
+        let south_moves: Vec<_> = self
+            .points()
+            .filter(|(_, ch)| *ch == 'v')
+            .filter(|(p, ch)| self.grid[self.next(p, *ch)] == '.')
+            .collect();
+        self.do_moves(&south_moves);
+
+        east_moves.len() + south_moves.len()
+    }
+}
+
+pub struct Day25;
+
+impl Solution for Day25 {
+    const DAY: u32 = 25;
+    type Input = World;
+    type Part1 = usize;
+    type Part2 = &'static str;
+
+    fn parse(input: &str) -> Result<World> {
+        input.parse()
+    }
+
+    /// The first step on which no sea cucumber moves.
+    fn part1(input: &World) -> usize {
+        Driver::new(input.clone()).until_fixpoint()
+    }
+
+    /// Day 25 has no second puzzle.
+    fn part2(_input: &World) -> &'static str {
+        "no part 2"
+    }
+}
+
+/// The sea cucumbers from the start until they stop moving, a frame a step.
+pub fn draw(world: &World) -> Vec<Picture> {
+    let mut frames = vec![world.picture()];
+    Driver::new(world.clone())
+        .observe(|_, world, _| frames.push(world.picture()))
+        .until_fixpoint();
+    frames
+}
@@ -0,0 +1,442 @@
+//! A dense two-dimensional grid, stored row-major in a single `Vec`.
+//!
+//! Cells are addressed by `(x, y)` with `x` the column and `y` the row, both counting from the
+//! top-left corner. What lies past the edges is chosen per grid with a `Boundary`.
+
+use std::fmt::{Display, Formatter};
+use std::iter::StepBy;
+use std::ops::{Index, IndexMut};
+use std::slice::{ChunksExact, Iter, IterMut};
+use std::str::FromStr;
+use thiserror::Error;
+
+/// Offsets of the four edge-adjacent neighbours, in reading order.
+pub const NEIGHBOURS4: [(i64, i64); 4] = [(0, -1), (-1, 0), (1, 0), (0, 1)];
+
+/// Offsets of all eight neighbours, diagonals included, in reading order.
+pub const NEIGHBOURS8: [(i64, i64); 8] = [
+    (-1, -1),
+    (0, -1),
+    (1, -1),
+    (-1, 0),
+    (1, 0),
+    (-1, 1),
+    (0, 1),
+    (1, 1),
+];
+
+/// What lies beyond the edges of a grid.
+#[derive(Clone, Eq, PartialEq, Debug, Hash)]
+pub enum Boundary<T> {
+    /// Nothing: positions outside the grid don't exist.
+    Bounded,
+    /// The grid wraps around like a torus, so leaving one edge re-enters at the opposite one.
+    Toroidal,
+    /// The grid goes on forever, and every cell outside it holds this value.
+    Default(T),
+}
+
+#[derive(Clone, Eq, PartialEq, Debug, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+    boundary: Boundary<T>,
+}
+
+impl<T: Clone> Grid<T> {
+    /// A `width` × `height` grid with every cell set to `fill`.
+    pub fn new(width: usize, height: usize, fill: T) -> Self {
+        Grid::from_fn(width, height, |_| fill.clone())
+    }
+}
+
+impl<T> Grid<T> {
+    /// A grid whose cells are `f((x, y))`, generated in reading order.
+    pub fn from_fn(width: usize, height: usize, mut f: impl FnMut((usize, usize)) -> T) -> Self {
+        let cells = (0..height)
+            .flat_map(|y| (0..width).map(move |x| (x, y)))
+            .map(&mut f)
+            .collect();
+        Grid {
+            width,
+            height,
+            cells,
+            boundary: Boundary::Bounded,
+        }
+    }
+
+    /// Builds a grid from its rows, which must all be the same length.
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Self {
+        let height = rows.len();
+        let width = rows.first().map_or(0, Vec::len);
+        assert!(
+            rows.iter().all(|row| row.len() == width),
+            "grid rows must all be the same length"
+        );
+        Grid {
+            width,
+            height,
+            cells: rows.into_iter().flatten().collect(),
+            boundary: Boundary::Bounded,
+        }
+    }
+
+    pub fn with_boundary(mut self, boundary: Boundary<T>) -> Self {
+        self.boundary = boundary;
+        self
+    }
+
+    /// The same grid, wrapping around at the edges.
+    pub fn wrapping(self) -> Self {
+        self.with_boundary(Boundary::Toroidal)
+    }
+
+    /// The same grid, surrounded on all sides by infinitely many cells holding `value`.
+    pub fn with_default(self, value: T) -> Self {
+        self.with_boundary(Boundary::Default(value))
+    }
+
+    pub fn boundary(&self) -> &Boundary<T> {
+        &self.boundary
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, (x, y): (usize, usize)) -> bool {
+        x < self.width && y < self.height
+    }
+
+    pub fn get(&self, p: (usize, usize)) -> Option<&T> {
+        if self.contains(p) {
+            Some(&self.cells[p.1 * self.width + p.0])
+        } else {
+            None
+        }
+    }
+
+    pub fn get_mut(&mut self, p: (usize, usize)) -> Option<&mut T> {
+        if self.contains(p) {
+            Some(&mut self.cells[p.1 * self.width + p.0])
+        } else {
+            None
+        }
+    }
+
+    /// The cell at a position that may lie off the grid, resolved through the boundary: wrapped
+    /// for a toroidal grid, the default value for an infinite one, and `None` otherwise.
+    pub fn at(&self, (x, y): (i64, i64)) -> Option<&T> {
+        match self.resolve((x, y)) {
+            Some(p) => self.get(p),
+            None => match &self.boundary {
+                Boundary::Default(value) => Some(value),
+                _ => None,
+            },
+        }
+    }
+
+    /// Maps a signed position to the cell it denotes, wrapping if the grid is toroidal.
+    fn resolve(&self, (x, y): (i64, i64)) -> Option<(usize, usize)> {
+        let (w, h) = (self.width as i64, self.height as i64);
+        match self.boundary {
+            Boundary::Toroidal if w > 0 && h > 0 => {
+                Some((x.rem_euclid(w) as usize, y.rem_euclid(h) as usize))
+            }
+            _ if (0..w).contains(&x) && (0..h).contains(&y) => Some((x as usize, y as usize)),
+            _ => None,
+        }
+    }
+
+    /// The cell `(dx, dy)` away from `p`, wrapping on a toroidal grid. `None` if that lands off
+    /// the grid, even when the grid has a default value there.
+    pub fn offset(&self, (x, y): (usize, usize), (dx, dy): (i64, i64)) -> Option<(usize, usize)> {
+        self.resolve((x as i64 + dx, y as i64 + dy))
+    }
+
+    /// The positions of the edge-adjacent neighbours of `p` that are on the grid.
+    pub fn neighbours4(&self, p: (usize, usize)) -> impl Iterator<Item = (usize, usize)> + '_ {
+        NEIGHBOURS4.iter().filter_map(move |&d| self.offset(p, d))
+    }
+
+    /// The positions of all eight neighbours of `p` that are on the grid.
+    pub fn neighbours8(&self, p: (usize, usize)) -> impl Iterator<Item = (usize, usize)> + '_ {
+        NEIGHBOURS8.iter().filter_map(move |&d| self.offset(p, d))
+    }
+
+    /// Every position on the grid, in reading order.
+    pub fn positions(&self) -> impl Iterator<Item = (usize, usize)> {
+        let width = self.width;
+        (0..self.height).flat_map(move |y| (0..width).map(move |x| (x, y)))
+    }
+
+    /// The cells in reading order.
+    pub fn iter(&self) -> Iter<'_, T> {
+        self.cells.iter()
+    }
+
+    pub fn iter_mut(&mut self) -> IterMut<'_, T> {
+        self.cells.iter_mut()
+    }
+
+    /// The cells in reading order, alongside their positions.
+    pub fn cells(&self) -> impl Iterator<Item = ((usize, usize), &T)> {
+        self.positions().zip(self.cells.iter())
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> ChunksExact<'_, T> {
+        self.cells.chunks_exact(self.width.max(1))
+    }
+
+    pub fn column(&self, x: usize) -> StepBy<Iter<'_, T>> {
+        assert!(x < self.width, "column {} is off the grid", x);
+        self.cells[x..].iter().step_by(self.width)
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = StepBy<Iter<'_, T>>> {
+        (0..self.width).map(move |x| self.column(x))
+    }
+
+    /// Converts each cell with `f`, keeping the shape and boundary.
+    pub fn map<U>(&self, mut f: impl FnMut(&T) -> U) -> Grid<U> {
+        let boundary = match &self.boundary {
+            Boundary::Bounded => Boundary::Bounded,
+            Boundary::Toroidal => Boundary::Toroidal,
+            Boundary::Default(value) => Boundary::Default(f(value)),
+        };
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+            boundary,
+        }
+    }
+}
+
+impl<T> Index<(usize, usize)> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, p: (usize, usize)) -> &Self::Output {
+        self.get(p)
+            .unwrap_or_else(|| panic!("{:?} is off the {}x{} grid", p, self.width, self.height))
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Grid<T> {
+    fn index_mut(&mut self, p: (usize, usize)) -> &mut Self::Output {
+        let (width, height) = (self.width, self.height);
+        self.get_mut(p)
+            .unwrap_or_else(|| panic!("{:?} is off the {}x{} grid", p, width, height))
+    }
+}
+
+/// Cell types that have a one-character text form.
+pub trait Cell: Sized {
+    fn from_char(ch: char) -> Option<Self>;
+
+    fn to_char(&self) -> char;
+}
+
+impl Cell for char {
+    fn from_char(ch: char) -> Option<Self> {
+        Some(ch)
+    }
+
+    fn to_char(&self) -> char {
+        *self
+    }
+}
+
+/// `#` for set, `.` for clear.
+impl Cell for bool {
+    fn from_char(ch: char) -> Option<Self> {
+        match ch {
+            '#' => Some(true),
+            '.' => Some(false),
+            _ => None,
+        }
+    }
+
+    fn to_char(&self) -> char {
+        if *self {
+            '#'
+        } else {
+            '.'
+        }
+    }
+}
+
+/// Single decimal digits. Values above 9 have no digit, so they display as `*`.
+macro_rules! digit_cell {
+    ($($t:ty),*) => {$(
+        impl Cell for $t {
+            fn from_char(ch: char) -> Option<Self> {
+                ch.to_digit(10).map(|d| d as $t)
+            }
+
+            fn to_char(&self) -> char {
+                u32::try_from(*self)
+                    .ok()
+                    .and_then(|d| char::from_digit(d, 10))
+                    .unwrap_or('*')
+            }
+        }
+    )*};
+}
+
+digit_cell!(u8, u16, u32, u64, usize, i32, i64);
+
+#[derive(Clone, Eq, PartialEq, Debug, Error)]
+pub enum ParseGridError {
+    #[error("line {line} has {found} cells, expected {expected}")]
+    Ragged {
+        line: usize,
+        expected: usize,
+        found: usize,
+    },
+    #[error("line {line}, column {column}: unexpected {ch:?}")]
+    BadCell {
+        line: usize,
+        column: usize,
+        ch: char,
+    },
+}
+
+/// Parses one character per cell and one line per row. Lines and columns in errors count from 1.
+impl<T: Cell> FromStr for Grid<T> {
+    type Err = ParseGridError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut rows = Vec::new();
+        for (y, l) in s.lines().enumerate() {
+            let row = l
+                .chars()
+                .enumerate()
+                .map(|(x, ch)| {
+                    T::from_char(ch).ok_or(ParseGridError::BadCell {
+                        line: y + 1,
+                        column: x + 1,
+                        ch,
+                    })
+                })
+                .collect::<Result<Vec<T>, _>>()?;
+            if let Some(first) = rows.first().map(Vec::len) {
+                if row.len() != first {
+                    return Err(ParseGridError::Ragged {
+                        line: y + 1,
+                        expected: first,
+                        found: row.len(),
+                    });
+                }
+            }
+            rows.push(row);
+        }
+        Ok(Grid::from_rows(rows))
+    }
+}
+
+impl<T: Cell> Display for Grid<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        for row in self.rows() {
+            for cell in row {
+                write!(f, "{}", cell.to_char())?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Boundary, Grid, ParseGridError};
+
+    const DIGITS: &str = "123\n456\n";
+
+    #[test]
+    fn parse_and_index() {
+        let g: Grid<u32> = DIGITS.parse().unwrap();
+        assert_eq!((3, 2), (g.width(), g.height()));
+        assert_eq!(6, g[(2, 1)]);
+        assert_eq!(Some(&2), g.get((1, 0)));
+        assert_eq!(None, g.get((3, 0)));
+        assert_eq!(DIGITS, g.to_string());
+
+        let image: Grid<bool> = "#.\n.#".parse().unwrap();
+        assert!(image[(1, 1)] && !image[(0, 1)]);
+        assert_eq!("#.\n.#\n", image.to_string());
+    }
+
+    #[test]
+    fn parse_errors() {
+        assert_eq!(
+            Err(ParseGridError::Ragged {
+                line: 2,
+                expected: 3,
+                found: 2
+            }),
+            "123\n45".parse::<Grid<u8>>()
+        );
+        assert_eq!(
+            Err(ParseGridError::BadCell {
+                line: 1,
+                column: 2,
+                ch: 'x'
+            }),
+            "1x3".parse::<Grid<u8>>()
+        );
+    }
+
+    #[test]
+    fn rows_and_columns() {
+        let g: Grid<u32> = DIGITS.parse().unwrap();
+        assert_eq!(
+            vec![&[1, 2, 3][..], &[4, 5, 6]],
+            g.rows().collect::<Vec<_>>()
+        );
+        assert_eq!(vec![2, 5], g.column(1).copied().collect::<Vec<_>>());
+        let sums: Vec<u32> = g.columns().map(|c| c.sum()).collect();
+        assert_eq!(vec![5, 7, 9], sums);
+        assert_eq!(((2, 1), &6), g.cells().last().unwrap());
+    }
+
+    #[test]
+    fn neighbours() {
+        let g = Grid::new(3, 3, 0);
+        let mut n: Vec<_> = g.neighbours4((0, 0)).collect();
+        n.sort();
+        assert_eq!(vec![(0, 1), (1, 0)], n);
+        assert_eq!(3, g.neighbours8((2, 2)).count());
+        assert_eq!(8, g.neighbours8((1, 1)).count());
+
+        let g = g.wrapping();
+        assert_eq!(8, g.neighbours8((0, 0)).count());
+        assert_eq!(Some((0, 1)), g.offset((2, 1), (1, 0)));
+        assert_eq!(Some((2, 2)), g.offset((0, 0), (-1, -1)));
+    }
+
+    #[test]
+    fn boundaries() {
+        let g = Grid::from_fn(2, 2, |(x, y)| x + 10 * y);
+        assert_eq!(None, g.at((-1, 0)));
+        assert_eq!(Some(&11), g.at((1, 1)));
+
+        let torus = g.clone().wrapping();
+        assert_eq!(Some(&10), torus.at((-2, 3)));
+
+        let infinite = g.with_default(7);
+        assert_eq!(Some(&7), infinite.at((100, -5)));
+        assert_eq!(Some(&1), infinite.at((1, 0)));
+        assert_eq!(&Boundary::Default(8), infinite.map(|x| x + 1).boundary());
+        assert_eq!(None, infinite.offset((1, 1), (1, 0)));
+    }
+}
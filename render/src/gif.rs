@@ -0,0 +1,319 @@
+//! Animated GIFs: every frame shares one palette and loops forever.
+//!
+//! A GIF's palette holds at most 256 colours, so when the frames have more than that between them
+//! every colour is rounded to the nearest in a 6×6×6 cube, which is coarse but fine for heatmaps.
+
+use crate::{Picture, Rgb};
+use std::collections::HashMap;
+use std::io::ErrorKind;
+use std::path::Path;
+use std::time::Duration;
+use thiserror::Error;
+
+/// Codes in the LZW table can be at most this many bits.
+const MAX_CODE_BITS: u8 = 12;
+
+/// Frames too big for a GIF, which has 16 bits for each side.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Error)]
+#[error("{width}×{height} frames are too big for a GIF, which holds at most 65535×65535")]
+pub struct TooBig {
+    pub width: usize,
+    pub height: usize,
+}
+
+/// `frames` as an animated GIF, showing each for `delay`. Frames smaller than the largest are
+/// centred on it and padded with their top left pixel, which suits a grid that grows each step
+/// into surroundings all the same.
+pub fn gif(frames: &[Picture], delay: Duration) -> Result<Vec<u8>, TooBig> {
+    let width = frames.iter().map(|f| f.width).max().unwrap_or(0);
+    let height = frames.iter().map(|f| f.height).max().unwrap_or(0);
+    let (Ok(screen_width), Ok(screen_height)) = (u16::try_from(width), u16::try_from(height))
+    else {
+        return Err(TooBig { width, height });
+    };
+    let frames: Vec<Picture> = frames
+        .iter()
+        .map(|f| match f.pixels.first() {
+            Some(&corner) if (f.width, f.height) != (width, height) => {
+                f.centred(width, height, corner)
+            }
+            _ => f.clone(),
+        })
+        .collect();
+
+    let mut colours: Vec<Rgb> = frames
+        .iter()
+        .flat_map(|f| f.pixels.iter().copied())
+        .collect();
+    colours.sort_unstable();
+    colours.dedup();
+    let quantize = colours.len() > 256;
+    if quantize {
+        colours = colours.iter().map(|c| c.cube()).collect();
+        colours.sort_unstable();
+        colours.dedup();
+    }
+    let index: HashMap<Rgb, u8> = colours
+        .iter()
+        .enumerate()
+        .map(|(i, &c)| (c, i as u8))
+        .collect();
+
+    // The palette has 2^bits entries, unused ones black
+    let bits = (1..=8).find(|b| colours.len() <= 1 << b).unwrap();
+    let mut out = b"GIF89a".to_vec();
+    out.extend(screen_width.to_le_bytes());
+    out.extend(screen_height.to_le_bytes());
+    // A global palette of 2^bits colours, unsorted, with background colour 0 and square pixels
+    out.extend([0x80 | (bits - 1) << 4 | (bits - 1), 0, 0]);
+    for i in 0..1 << bits {
+        let Rgb(r, g, b) = colours.get(i).copied().unwrap_or(Rgb::BLACK);
+        out.extend([r, g, b]);
+    }
+    if frames.len() > 1 {
+        // Loop forever
+        out.extend([0x21, 0xff, 11]);
+        out.extend(b"NETSCAPE2.0");
+        out.extend([3, 1, 0, 0, 0]);
+    }
+
+    let centiseconds = (delay.as_millis() / 10).min(u16::MAX as u128) as u16;
+    let min_code_bits = bits.max(2);
+    for frame in &frames {
+        // A graphic control extension for the delay, with no transparency
+        out.extend([0x21, 0xf9, 4, 0]);
+        out.extend(centiseconds.to_le_bytes());
+        out.extend([0, 0]);
+
+        // The image, filling the screen, with no palette of its own
+        out.push(0x2c);
+        out.extend([0, 0, 0, 0]);
+        out.extend(screen_width.to_le_bytes());
+        out.extend(screen_height.to_le_bytes());
+        out.push(0);
+
+        let indices: Vec<u8> = frame
+            .pixels
+            .iter()
+            .map(|&c| index[&if quantize { c.cube() } else { c }])
+            .collect();
+        out.push(min_code_bits);
+        for block in lzw(&indices, min_code_bits).chunks(255) {
+            out.push(block.len() as u8);
+            out.extend(block);
+        }
+        out.push(0);
+    }
+    out.push(0x3b);
+    Ok(out)
+}
+
+pub fn write_gif(
+    path: impl AsRef<Path>,
+    frames: &[Picture],
+    delay: Duration,
+) -> std::io::Result<()> {
+    let gif = gif(frames, delay).map_err(|e| std::io::Error::new(ErrorKind::InvalidInput, e))?;
+    std::fs::write(path, gif)
+}
+
+/// Packs codes of varying widths into bytes, least significant bit first.
+#[derive(Default)]
+struct Bits {
+    bytes: Vec<u8>,
+    pending: u32,
+    count: u8,
+}
+
+impl Bits {
+    fn write(&mut self, code: u16, width: u8) {
+        self.pending |= (code as u32) << self.count;
+        self.count += width;
+        while self.count >= 8 {
+            self.bytes.push(self.pending as u8);
+            self.pending >>= 8;
+            self.count -= 8;
+        }
+    }
+
+    fn finish(mut self) -> Vec<u8> {
+        if self.count > 0 {
+            self.bytes.push(self.pending as u8);
+        }
+        self.bytes
+    }
+}
+
+/// GIF's variant of LZW: codes start one bit wider than `min_code_bits`, and grow a bit whenever
+/// the table outgrows them, until they reach 12 bits and the table is cleared to start again.
+fn lzw(indices: &[u8], min_code_bits: u8) -> Vec<u8> {
+    let clear = 1u16 << min_code_bits;
+    let end = clear + 1;
+    let mut table: HashMap<(u16, u8), u16> = HashMap::new();
+    let mut last_code = end;
+    let mut width = min_code_bits + 1;
+
+    let mut bits = Bits::default();
+    bits.write(clear, width);
+    let mut prefix: Option<u16> = None;
+    for &k in indices {
+        let Some(p) = prefix else {
+            prefix = Some(k as u16);
+            continue;
+        };
+        if let Some(&code) = table.get(&(p, k)) {
+            prefix = Some(code);
+            continue;
+        }
+        bits.write(p, width);
+        last_code += 1;
+        table.insert((p, k), last_code);
+        if last_code >= 1 << width {
+            width += 1;
+        }
+        if last_code == (1 << MAX_CODE_BITS) - 1 {
+            bits.write(clear, width.min(MAX_CODE_BITS));
+            table.clear();
+            last_code = end;
+            width = min_code_bits + 1;
+        }
+        prefix = Some(k as u16);
+    }
+    if let Some(p) = prefix {
+        bits.write(p, width);
+        // A decoder adds an entry on reading each code but the first after a clear, one behind
+        // the encoder, so it adds one more for this last code, which can widen the end code
+        if last_code > end && last_code + 1 >= 1 << width && width < MAX_CODE_BITS {
+            width += 1;
+        }
+    }
+    bits.write(end, width);
+    bits.finish()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{gif, lzw, TooBig};
+    use crate::{Picture, Rgb};
+    use std::time::Duration;
+
+    /// Decodes GIF LZW, the way a viewer would.
+    fn unlzw(data: &[u8], min_code_bits: u8) -> Vec<u8> {
+        let clear = 1usize << min_code_bits;
+        let end = clear + 1;
+        let mut table: Vec<Vec<u8>> = vec![];
+        let mut width = min_code_bits + 1;
+        let mut prev: Option<usize> = None;
+        let mut out = vec![];
+        let (mut at, mut pending, mut count) = (0, 0u32, 0);
+        loop {
+            while count < width {
+                pending |= (data[at] as u32) << count;
+                at += 1;
+                count += 8;
+            }
+            let code = (pending & ((1 << width) - 1)) as usize;
+            pending >>= width;
+            count -= width;
+
+            if code == clear {
+                table = (0..clear).map(|i| vec![i as u8]).collect();
+                table.extend([vec![], vec![]]);
+                width = min_code_bits + 1;
+                prev = None;
+                continue;
+            }
+            if code == end {
+                return out;
+            }
+            let entry = match (table.get(code), prev) {
+                (Some(entry), _) => entry.clone(),
+                (None, Some(p)) => {
+                    let mut entry = table[p].clone();
+                    entry.push(table[p][0]);
+                    entry
+                }
+                (None, None) => panic!("code {} before any other", code),
+            };
+            if let Some(p) = prev {
+                let mut added = table[p].clone();
+                added.push(entry[0]);
+                table.push(added);
+                if table.len() == 1 << width && width < 12 {
+                    width += 1;
+                }
+            }
+            out.extend(&entry);
+            prev = Some(code);
+        }
+    }
+
+    #[test]
+    fn lzw_round_trips() {
+        let cases: Vec<(Vec<u8>, u8)> = vec![
+            (vec![], 2),
+            (vec![1], 2),
+            (vec![0, 0, 0, 0, 0, 0, 0, 0, 0, 0], 2),
+            ((0..50_000u64).map(|i| (i * i / 7 % 4) as u8).collect(), 2),
+            ((0..50_000).map(|i| (i * 31 % 256) as u8).collect(), 8),
+            (
+                (0..100_000).map(|i| ((i ^ (i >> 5)) % 200) as u8).collect(),
+                8,
+            ),
+        ];
+        for (indices, bits) in cases {
+            assert_eq!(indices, unlzw(&lzw(&indices, bits), bits));
+        }
+        // Every length up to past the first clear, so the table ends at each size
+        let indices: Vec<u8> = (0..9000).map(|i| (i * 7 % 13 + i / 11 % 3) as u8).collect();
+        for len in 0..indices.len() {
+            assert_eq!(&indices[..len], unlzw(&lzw(&indices[..len], 4), 4));
+        }
+    }
+
+    #[test]
+    fn frames() {
+        let small = Picture::new(1, 1, Rgb::WHITE);
+        let mut big = Picture::new(3, 2, Rgb::BLACK);
+        big.set((2, 1), Rgb::RED);
+        let gif = gif(&[small, big], Duration::from_millis(250)).unwrap();
+        assert_eq!(b"GIF89a\x03\0\x02\0", &gif[..10]);
+        // Three colours need a palette of four
+        assert_eq!(0x80 | 1 << 4 | 1, gif[10]);
+        assert_eq!([0, 0, 0, 220, 40, 20, 255, 255, 255, 0, 0, 0], gif[13..25]);
+        assert_eq!(b"NETSCAPE2.0", &gif[28..39]);
+        assert_eq!(
+            2,
+            gif.windows(4).filter(|w| w == b"\x21\xf9\x04\x00").count()
+        );
+        assert_eq!([0x21, 0xf9, 4, 0, 25, 0], gif[44..50]);
+        assert_eq!(Some(&0x3b), gif.last());
+    }
+
+    #[test]
+    fn many_colours_are_quantized() {
+        let mut picture = Picture::new(30, 30, Rgb::BLACK);
+        for y in 0..30 {
+            for x in 0..30 {
+                picture.set((x, y), Rgb(x as u8 * 8, y as u8 * 8, 100));
+            }
+        }
+        let gif = gif(&[picture], Duration::ZERO).unwrap();
+        // 36 colours of the cube, in a palette of 64
+        assert_eq!(0x80 | 5 << 4 | 5, gif[10]);
+        assert!(!gif.windows(11).any(|w| w == b"NETSCAPE2.0"));
+    }
+
+    #[test]
+    fn sides_must_fit_in_16_bits() {
+        let wide = Picture::new(65536, 1, Rgb::BLACK);
+        assert_eq!(
+            Err(TooBig {
+                width: 65536,
+                height: 2
+            }),
+            gif(&[wide, Picture::new(1, 2, Rgb::BLACK)], Duration::ZERO)
+        );
+        assert!(gif(&[Picture::new(65535, 1, Rgb::BLACK)], Duration::ZERO).is_ok());
+    }
+}
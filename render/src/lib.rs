@@ -0,0 +1,319 @@
+//! Pictures of puzzle states: grids and sparse point sets drawn in colour, for looking at in a
+//! terminal or saving as images.
+//!
+//! A [`Picture`] is a rectangle of colours. It prints as ANSI truecolour text, two pixels to a
+//! character cell, and saves as a PNG, or with others as the frames of an animated GIF. Both
+//! encoders are written out here rather than pulled in, and favour simplicity over file size.
+
+mod gif;
+mod png;
+
+pub use gif::{gif, write_gif, TooBig};
+
+use grid::Grid;
+use std::fmt::Write as _;
+use std::path::Path;
+
+/// A colour, as red, green and blue.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash, Ord, PartialOrd)]
+pub struct Rgb(pub u8, pub u8, pub u8);
+
+impl Rgb {
+    pub const BLACK: Rgb = Rgb(0, 0, 0);
+    pub const WHITE: Rgb = Rgb(255, 255, 255);
+    pub const RED: Rgb = Rgb(220, 40, 20);
+    pub const GREEN: Rgb = Rgb(40, 180, 80);
+    pub const BLUE: Rgb = Rgb(40, 100, 220);
+    pub const GREY: Rgb = Rgb(40, 40, 40);
+
+    /// The nearest colour in a 6×6×6 cube, which all fit in a GIF's palette.
+    fn cube(self) -> Rgb {
+        let level = |c: u8| ((c as u32 * 5 + 127) / 255 * 51) as u8;
+        Rgb(level(self.0), level(self.1), level(self.2))
+    }
+}
+
+/// The colours of a heatmap, from coldest to hottest, evenly spaced along its scale.
+const HEAT: [Rgb; 5] = [
+    Rgb(16, 16, 64),
+    Rgb(32, 96, 200),
+    Rgb(40, 180, 120),
+    Rgb(250, 210, 40),
+    Rgb(220, 40, 20),
+];
+
+/// The colour of `t` on a heatmap's scale, from 0 for coldest to 1 for hottest.
+pub fn heat(t: f64) -> Rgb {
+    let t = t.clamp(0.0, 1.0) * (HEAT.len() - 1) as f64;
+    let i = (t as usize).min(HEAT.len() - 2);
+    let (a, b, f) = (HEAT[i], HEAT[i + 1], t - i as f64);
+    let mix = |a: u8, b: u8| (a as f64 + (b as f64 - a as f64) * f).round() as u8;
+    Rgb(mix(a.0, b.0), mix(a.1, b.1), mix(a.2, b.2))
+}
+
+/// A rectangle of colours, addressed by `(x, y)` from the top left like a [`Grid`].
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Picture {
+    width: usize,
+    height: usize,
+    pixels: Vec<Rgb>,
+}
+
+impl Picture {
+    /// A `width` × `height` picture filled with `background`.
+    pub fn new(width: usize, height: usize, background: Rgb) -> Self {
+        Picture {
+            width,
+            height,
+            pixels: vec![background; width * height],
+        }
+    }
+
+    /// A pixel for every cell of `grid`, coloured by `colour`.
+    pub fn from_grid<T>(grid: &Grid<T>, colour: impl FnMut(&T) -> Rgb) -> Self {
+        Picture {
+            width: grid.width(),
+            height: grid.height(),
+            pixels: grid.iter().map(colour).collect(),
+        }
+    }
+
+    /// A heatmap of `grid`, with its smallest values coldest and its largest hottest.
+    pub fn heatmap<T: Copy + Into<f64>>(grid: &Grid<T>) -> Self {
+        let (min, max) = grid
+            .iter()
+            .map(|&v| v.into())
+            .fold((f64::MAX, f64::MIN), |(lo, hi), v| (lo.min(v), hi.max(v)));
+        let span = if max > min { max - min } else { 1.0 };
+        Picture::from_grid(grid, |&v| heat((v.into() - min) / span))
+    }
+
+    /// `points` in `on` against `off`, just big enough to hold them all.
+    pub fn from_points<T: Copy + Into<i64>>(
+        points: impl IntoIterator<Item = (T, T)>,
+        on: Rgb,
+        off: Rgb,
+    ) -> Self {
+        let (points, bounds) = bounded(points);
+        let mut picture = Picture::new(bounds.width, bounds.height, off);
+        for p in points {
+            picture.set(bounds.place(p), on);
+        }
+        picture
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// The colour at `(x, y)`, which must be in the picture.
+    pub fn get(&self, (x, y): (usize, usize)) -> Rgb {
+        assert!(
+            x < self.width && y < self.height,
+            "{:?} is off the picture",
+            (x, y)
+        );
+        self.pixels[y * self.width + x]
+    }
+
+    /// Colours `(x, y)`, which must be in the picture.
+    pub fn set(&mut self, (x, y): (usize, usize), colour: Rgb) {
+        assert!(
+            x < self.width && y < self.height,
+            "{:?} is off the picture",
+            (x, y)
+        );
+        self.pixels[y * self.width + x] = colour;
+    }
+
+    /// Colours every one of `points` over what's already there, such as a path across a map.
+    pub fn highlight(&mut self, points: impl IntoIterator<Item = (usize, usize)>, colour: Rgb) {
+        for p in points {
+            self.set(p, colour);
+        }
+    }
+
+    /// The picture blown up so every pixel becomes a `factor` × `factor` square.
+    pub fn scaled(&self, factor: usize) -> Picture {
+        let width = self.width * factor;
+        let pixels = (0..self.height * factor)
+            .flat_map(|y| (0..width).map(move |x| (x / factor, y / factor)))
+            .map(|p| self.get(p))
+            .collect();
+        Picture {
+            width,
+            height: self.height * factor,
+            pixels,
+        }
+    }
+
+    /// The picture padded out to `width` × `height` with `fill`, keeping it in the middle.
+    fn centred(&self, width: usize, height: usize, fill: Rgb) -> Picture {
+        let (left, top) = ((width - self.width) / 2, (height - self.height) / 2);
+        let mut padded = Picture::new(width, height, fill);
+        for y in 0..self.height {
+            let at = (top + y) * width + left;
+            padded.pixels[at..at + self.width].copy_from_slice(self.row(y));
+        }
+        padded
+    }
+
+    fn row(&self, y: usize) -> &[Rgb] {
+        &self.pixels[y * self.width..(y + 1) * self.width]
+    }
+
+    /// The picture as text for a terminal that takes 24-bit ANSI colours. Each character is an
+    /// upper half block with the top pixel as its foreground and the one below as its background,
+    /// so a pixel comes out about square.
+    pub fn ansi(&self) -> String {
+        let mut out = String::new();
+        for y in (0..self.height).step_by(2) {
+            for x in 0..self.width {
+                let Rgb(r, g, b) = self.get((x, y));
+                write!(out, "\x1b[38;2;{};{};{}m", r, g, b).unwrap();
+                if y + 1 < self.height {
+                    let Rgb(r, g, b) = self.get((x, y + 1));
+                    write!(out, "\x1b[48;2;{};{};{}m", r, g, b).unwrap();
+                }
+                out.push('▀');
+            }
+            out.push_str("\x1b[0m\n");
+        }
+        out
+    }
+
+    /// The picture as a PNG file.
+    pub fn png(&self) -> Vec<u8> {
+        png::encode(self)
+    }
+
+    pub fn write_png(&self, path: impl AsRef<Path>) -> std::io::Result<()> {
+        std::fs::write(path, self.png())
+    }
+}
+
+/// `points` drawn as text, with `on` for the points and `off` for the gaps between them, just big
+/// enough to hold them all.
+pub fn text<T: Copy + Into<i64>>(
+    points: impl IntoIterator<Item = (T, T)>,
+    on: char,
+    off: char,
+) -> String {
+    let (points, bounds) = bounded(points);
+    let mut rows = vec![vec![off; bounds.width]; bounds.height];
+    for p in points {
+        let (x, y) = bounds.place(p);
+        rows[y][x] = on;
+    }
+    rows.iter()
+        .map(|row| row.iter().collect::<String>())
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// The smallest rectangle holding some points.
+struct Bounds {
+    left: i64,
+    top: i64,
+    width: usize,
+    height: usize,
+}
+
+impl Bounds {
+    /// Where `(x, y)` is within the bounds, counting from their top left.
+    fn place(&self, (x, y): (i64, i64)) -> (usize, usize) {
+        ((x - self.left) as usize, (y - self.top) as usize)
+    }
+}
+
+/// `points`, and the bounds around them.
+fn bounded<T: Into<i64>>(points: impl IntoIterator<Item = (T, T)>) -> (Vec<(i64, i64)>, Bounds) {
+    let points: Vec<(i64, i64)> = points
+        .into_iter()
+        .map(|(x, y)| (x.into(), y.into()))
+        .collect();
+    let bounds = match (
+        points.iter().map(|p| p.0).min(),
+        points.iter().map(|p| p.0).max(),
+        points.iter().map(|p| p.1).min(),
+        points.iter().map(|p| p.1).max(),
+    ) {
+        (Some(left), Some(right), Some(top), Some(bottom)) => Bounds {
+            left,
+            top,
+            width: (right - left + 1) as usize,
+            height: (bottom - top + 1) as usize,
+        },
+        _ => Bounds {
+            left: 0,
+            top: 0,
+            width: 0,
+            height: 0,
+        },
+    };
+    (points, bounds)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{heat, text, Picture, Rgb};
+    use grid::Grid;
+
+    #[test]
+    fn grids_and_heatmaps() {
+        let grid: Grid<u32> = "19\n55\n".parse().unwrap();
+        let picture = Picture::heatmap(&grid);
+        assert_eq!((2, 2), (picture.width(), picture.height()));
+        assert_eq!(heat(0.0), picture.get((0, 0)));
+        assert_eq!(heat(1.0), picture.get((1, 0)));
+        assert_eq!(heat(0.5), picture.get((0, 1)));
+        assert_eq!(Rgb(40, 180, 120), heat(0.5));
+
+        let bools = grid.map(|&v| v > 4);
+        let picture = Picture::from_grid(&bools, |&b| if b { Rgb::WHITE } else { Rgb::BLACK });
+        assert_eq!(Rgb::BLACK, picture.get((0, 0)));
+        assert_eq!(Rgb::WHITE, picture.get((1, 1)));
+    }
+
+    #[test]
+    fn points_are_bounded() {
+        let points = [(-1, 2), (1, 3)];
+        let picture = Picture::from_points(points, Rgb::WHITE, Rgb::BLACK);
+        assert_eq!((3, 2), (picture.width(), picture.height()));
+        assert_eq!(Rgb::WHITE, picture.get((0, 0)));
+        assert_eq!(Rgb::BLACK, picture.get((1, 0)));
+        assert_eq!(Rgb::WHITE, picture.get((2, 1)));
+        assert_eq!("#..\n..#", text(points, '#', '.'));
+        assert_eq!("", text(Vec::<(i32, i32)>::new(), '#', '.'));
+    }
+
+    #[test]
+    fn scaling_and_highlighting() {
+        let mut picture = Picture::new(2, 1, Rgb::BLACK);
+        picture.highlight([(1, 0)], Rgb::RED);
+        let big = picture.scaled(2);
+        assert_eq!((4, 2), (big.width(), big.height()));
+        assert_eq!(Rgb::BLACK, big.get((1, 1)));
+        assert_eq!(Rgb::RED, big.get((2, 1)));
+
+        let padded = picture.centred(4, 3, Rgb::WHITE);
+        assert_eq!(Rgb::WHITE, padded.get((0, 1)));
+        assert_eq!(Rgb::BLACK, padded.get((1, 1)));
+        assert_eq!(Rgb::RED, padded.get((2, 1)));
+        assert_eq!(Rgb::WHITE, padded.get((2, 0)));
+    }
+
+    #[test]
+    fn ansi_takes_two_rows_a_line() {
+        let mut picture = Picture::new(1, 3, Rgb::BLACK);
+        picture.set((0, 1), Rgb::WHITE);
+        assert_eq!(
+            "\x1b[38;2;0;0;0m\x1b[48;2;255;255;255m▀\x1b[0m\n\x1b[38;2;0;0;0m▀\x1b[0m\n",
+            picture.ansi()
+        );
+    }
+}
@@ -0,0 +1,142 @@
+//! Just enough of PNG to write a picture: 8-bit RGB, unfiltered, with the image data in
+//! uncompressed deflate blocks. The files come out about as big as the raw pixels, which is fine
+//! for puzzle-sized pictures, and every viewer reads them.
+
+use crate::Picture;
+
+const SIGNATURE: [u8; 8] = [0x89, b'P', b'N', b'G', b'\r', b'\n', 0x1a, b'\n'];
+
+/// The most a stored deflate block can hold.
+const MAX_STORED: usize = 0xffff;
+
+pub fn encode(picture: &Picture) -> Vec<u8> {
+    let mut ihdr = vec![];
+    ihdr.extend((picture.width as u32).to_be_bytes());
+    ihdr.extend((picture.height as u32).to_be_bytes());
+    // 8 bits a channel, RGB, deflate, no filtering, no interlacing
+    ihdr.extend([8, 2, 0, 0, 0]);
+
+    // Every row starts with its filter type, which is always 0 for none
+    let mut raw = Vec::with_capacity((picture.width * 3 + 1) * picture.height);
+    for y in 0..picture.height {
+        raw.push(0);
+        raw.extend(picture.row(y).iter().flat_map(|c| [c.0, c.1, c.2]));
+    }
+
+    let mut out = SIGNATURE.to_vec();
+    chunk(&mut out, b"IHDR", &ihdr);
+    chunk(&mut out, b"IDAT", &zlib_stored(&raw));
+    chunk(&mut out, b"IEND", &[]);
+    out
+}
+
+/// Appends a chunk: its length, type, data, and a checksum of the type and data.
+fn chunk(out: &mut Vec<u8>, kind: &[u8; 4], data: &[u8]) {
+    out.extend((data.len() as u32).to_be_bytes());
+    let start = out.len();
+    out.extend(kind);
+    out.extend(data);
+    let crc = crc32(&out[start..]);
+    out.extend(crc.to_be_bytes());
+}
+
+/// `data` in a zlib stream of stored, which is to say uncompressed, deflate blocks.
+pub(crate) fn zlib_stored(data: &[u8]) -> Vec<u8> {
+    // Deflate with a 32K window, no preset dictionary, and a header check that makes the pair a
+    // multiple of 31
+    let mut out = vec![0x78, 0x01];
+    let mut blocks = data.chunks(MAX_STORED).peekable();
+    if blocks.peek().is_none() {
+        out.extend([1, 0, 0, 0xff, 0xff]);
+    }
+    while let Some(block) = blocks.next() {
+        out.push(blocks.peek().is_none() as u8);
+        let len = block.len() as u16;
+        out.extend(len.to_le_bytes());
+        out.extend((!len).to_le_bytes());
+        out.extend(block);
+    }
+    out.extend(adler32(data).to_be_bytes());
+    out
+}
+
+pub(crate) fn crc32(data: &[u8]) -> u32 {
+    let mut crc = !0u32;
+    for &byte in data {
+        crc ^= byte as u32;
+        for _ in 0..8 {
+            crc = if crc & 1 == 1 {
+                (crc >> 1) ^ 0xedb8_8320
+            } else {
+                crc >> 1
+            };
+        }
+    }
+    !crc
+}
+
+pub(crate) fn adler32(data: &[u8]) -> u32 {
+    let (mut a, mut b) = (1u32, 0u32);
+    for &byte in data {
+        a = (a + byte as u32) % 65521;
+        b = (b + a) % 65521;
+    }
+    (b << 16) | a
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{adler32, crc32, encode, zlib_stored, MAX_STORED};
+    use crate::{Picture, Rgb};
+
+    #[test]
+    fn checksums() {
+        assert_eq!(0xae42_6082, crc32(b"IEND"));
+        assert_eq!(0xcbf4_3926, crc32(b"123456789"));
+        assert_eq!(0x11e6_0398, adler32(b"Wikipedia"));
+    }
+
+    /// Reads back the stored blocks of a zlib stream.
+    fn unstore(zlib: &[u8]) -> Vec<u8> {
+        let mut data = vec![];
+        let mut at = 2;
+        loop {
+            let last = zlib[at] == 1;
+            let len = u16::from_le_bytes([zlib[at + 1], zlib[at + 2]]) as usize;
+            let nlen = u16::from_le_bytes([zlib[at + 3], zlib[at + 4]]) as usize;
+            assert_eq!(len, !nlen & 0xffff);
+            data.extend(&zlib[at + 5..at + 5 + len]);
+            at += 5 + len;
+            if last {
+                break;
+            }
+        }
+        assert_eq!(adler32(&data).to_be_bytes(), zlib[at..]);
+        data
+    }
+
+    #[test]
+    fn stored_blocks_round_trip() {
+        assert_eq!(0, (0x78 * 256 + 0x01) % 31);
+        for len in [0, 1, MAX_STORED, MAX_STORED + 1, 3 * MAX_STORED + 7] {
+            let data: Vec<u8> = (0..len).map(|i| (i * 7 % 256) as u8).collect();
+            assert_eq!(data, unstore(&zlib_stored(&data)));
+        }
+    }
+
+    #[test]
+    fn pngs() {
+        let mut picture = Picture::new(2, 1, Rgb::BLACK);
+        picture.set((1, 0), Rgb(1, 2, 3));
+        let png = encode(&picture);
+        assert_eq!(b"\x89PNG\r\n\x1a\n", &png[..8]);
+        assert_eq!(
+            b"\0\0\0\x0dIHDR\0\0\0\x02\0\0\0\x01\x08\x02\0\0\0",
+            &png[8..29]
+        );
+        assert_eq!(b"IDAT", &png[37..41]);
+        let idat_len = u32::from_be_bytes(png[33..37].try_into().unwrap()) as usize;
+        assert_eq!(vec![0, 0, 0, 0, 1, 2, 3], unstore(&png[41..41 + idat_len]));
+        assert_eq!(b"\0\0\0\0IEND\xae\x42\x60\x82", &png[png.len() - 12..]);
+    }
+}
@@ -0,0 +1,364 @@
+//! Shortest-path searches over implicit graphs.
+//!
+//! Graphs are never built up front: each search takes a start node and a closure giving the
+//! neighbours of a node, so the nodes can be anything hashable, including whole puzzle states.
+
+use std::cmp::Ordering;
+use std::collections::{BinaryHeap, HashMap, VecDeque};
+use std::hash::Hash;
+use std::ops::Add;
+
+/// Edge weights and path lengths.
+pub trait Cost: Copy + Ord + Add<Output = Self> {
+    const ZERO: Self;
+}
+
+macro_rules! cost {
+    ($($t:ty),*) => {$(
+        impl Cost for $t {
+            const ZERO: Self = 0;
+        }
+    )*};
+}
+
+cost!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+
+/// Everything a search learned about the graph.
+#[derive(Clone, Debug)]
+pub struct Search<N, C> {
+    /// The cost of the cheapest known path to each reached node. Exact for every node the search
+    /// expanded; if it stopped early, nodes still on the frontier may have tentative costs.
+    pub dist: HashMap<N, C>,
+    /// The node each reached node was reached from along its cheapest known path.
+    pub prev: HashMap<N, N>,
+    /// The node that satisfied the goal predicate, if the search stopped at one.
+    pub goal: Option<N>,
+}
+
+impl<N: Clone + Eq + Hash, C: Copy> Search<N, C> {
+    fn new() -> Self {
+        Search {
+            dist: HashMap::new(),
+            prev: HashMap::new(),
+            goal: None,
+        }
+    }
+
+    pub fn cost(&self, node: &N) -> Option<C> {
+        self.dist.get(node).copied()
+    }
+
+    /// The path from the start to `target`, both included, or `None` if it wasn't reached.
+    pub fn path_to(&self, target: &N) -> Option<Vec<N>> {
+        if !self.dist.contains_key(target) {
+            return None;
+        }
+        let mut path = vec![target.clone()];
+        while let Some(p) = self.prev.get(path.last().unwrap()) {
+            path.push(p.clone());
+        }
+        path.reverse();
+        Some(path)
+    }
+
+    /// The path to the goal the search stopped at.
+    pub fn goal_path(&self) -> Option<Vec<N>> {
+        self.path_to(self.goal.as_ref()?)
+    }
+
+    /// The cost of reaching the goal the search stopped at.
+    pub fn goal_cost(&self) -> Option<C> {
+        self.cost(self.goal.as_ref()?)
+    }
+}
+
+/// A heap entry: the cheapest `priority` pops first, and ties go to the earliest pushed.
+struct Visit<N, C> {
+    priority: C,
+    cost: C,
+    seq: u64,
+    node: N,
+}
+
+impl<N, C: Ord> Ord for Visit<N, C> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other
+            .priority
+            .cmp(&self.priority)
+            .then_with(|| other.seq.cmp(&self.seq))
+    }
+}
+
+impl<N, C: Ord> PartialOrd for Visit<N, C> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<N, C: Ord> PartialEq for Visit<N, C> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl<N, C: Ord> Eq for Visit<N, C> {}
+
+/// Dijkstra's algorithm: cheapest paths from `start` under non-negative edge costs.
+///
+/// Nodes are expanded in order of cost, and the search stops at the first one satisfying
+/// `goal`; pass `|_| false` to reach everything.
+pub fn dijkstra<N, C, I>(
+    start: N,
+    neighbours: impl FnMut(&N) -> I,
+    goal: impl FnMut(&N) -> bool,
+) -> Search<N, C>
+where
+    N: Clone + Eq + Hash,
+    C: Cost,
+    I: IntoIterator<Item = (N, C)>,
+{
+    astar(start, neighbours, |_| C::ZERO, goal)
+}
+
+/// A* search: Dijkstra's algorithm guided by `heuristic`, an estimate of the remaining cost to a
+/// goal. The path found is the cheapest as long as the heuristic never overestimates.
+pub fn astar<N, C, I>(
+    start: N,
+    mut neighbours: impl FnMut(&N) -> I,
+    mut heuristic: impl FnMut(&N) -> C,
+    mut goal: impl FnMut(&N) -> bool,
+) -> Search<N, C>
+where
+    N: Clone + Eq + Hash,
+    C: Cost,
+    I: IntoIterator<Item = (N, C)>,
+{
+    let mut ret = Search::new();
+    let mut heap = BinaryHeap::new();
+    let mut seq = 0;
+
+    ret.dist.insert(start.clone(), C::ZERO);
+    heap.push(Visit {
+        priority: heuristic(&start),
+        cost: C::ZERO,
+        seq,
+        node: start,
+    });
+
+    while let Some(Visit { cost, node, .. }) = heap.pop() {
+        // Stale entry: a cheaper route to this node was found after it was pushed
+        if ret.dist[&node] < cost {
+            continue;
+        }
+        if goal(&node) {
+            ret.goal = Some(node);
+            break;
+        }
+        for (n, step) in neighbours(&node) {
+            let d = cost + step;
+            if ret.dist.get(&n).is_none_or(|&known| d < known) {
+                ret.dist.insert(n.clone(), d);
+                ret.prev.insert(n.clone(), node.clone());
+                seq += 1;
+                heap.push(Visit {
+                    priority: d + heuristic(&n),
+                    cost: d,
+                    seq,
+                    node: n,
+                });
+            }
+        }
+    }
+    ret
+}
+
+/// Breadth-first search: fewest-edge paths from `start`, stopping at the first node satisfying
+/// `goal`.
+pub fn bfs<N, I>(
+    start: N,
+    mut neighbours: impl FnMut(&N) -> I,
+    mut goal: impl FnMut(&N) -> bool,
+) -> Search<N, usize>
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = N>,
+{
+    let mut ret = Search::new();
+    let mut queue = VecDeque::new();
+
+    ret.dist.insert(start.clone(), 0);
+    queue.push_back(start);
+
+    while let Some(node) = queue.pop_front() {
+        if goal(&node) {
+            ret.goal = Some(node);
+            break;
+        }
+        let d = ret.dist[&node] + 1;
+        for n in neighbours(&node) {
+            if !ret.dist.contains_key(&n) {
+                ret.dist.insert(n.clone(), d);
+                ret.prev.insert(n.clone(), node.clone());
+                queue.push_back(n);
+            }
+        }
+    }
+    ret
+}
+
+/// A fewest-edge path from `start` to `target`, found by growing breadth-first frontiers from
+/// both ends until they meet. The graph must be undirected: `neighbours` is used both ways.
+pub fn bidirectional<N, I>(
+    start: N,
+    target: N,
+    mut neighbours: impl FnMut(&N) -> I,
+) -> Option<Vec<N>>
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = N>,
+{
+    // Each side maps the nodes it has reached to their predecessor towards its own end and
+    // their distance from it
+    type Side<N> = HashMap<N, (Option<N>, usize)>;
+    let mut sides: [Side<N>; 2] = [HashMap::new(), HashMap::new()];
+    let mut frontiers = [vec![start.clone()], vec![target.clone()]];
+    sides[0].insert(start, (None, 0));
+    sides[1].insert(target.clone(), (None, 0));
+
+    let mut meeting = sides[0].contains_key(&target).then_some(target);
+    while meeting.is_none() {
+        // Expand the smaller frontier by one whole layer
+        let s = if frontiers[0].len() <= frontiers[1].len() {
+            0
+        } else {
+            1
+        };
+        if frontiers[s].is_empty() {
+            return None;
+        }
+        // The first meeting found needn't be the best, so finish the layer and keep the
+        // shortest; once a layer meets the other side, no later one can do better
+        let mut best: Option<(usize, N)> = None;
+        let mut next = Vec::new();
+        for node in std::mem::take(&mut frontiers[s]) {
+            let d = sides[s][&node].1 + 1;
+            for n in neighbours(&node) {
+                if sides[s].contains_key(&n) {
+                    continue;
+                }
+                sides[s].insert(n.clone(), (Some(node.clone()), d));
+                if let Some(&(_, other)) = sides[1 - s].get(&n) {
+                    if best.as_ref().is_none_or(|(len, _)| d + other < *len) {
+                        best = Some((d + other, n.clone()));
+                    }
+                }
+                next.push(n);
+            }
+        }
+        frontiers[s] = next;
+        meeting = best.map(|(_, n)| n);
+    }
+    let meeting = meeting?;
+
+    let walk = |side: &Side<N>| {
+        let mut ret = vec![];
+        let mut current = side[&meeting].0.clone();
+        while let Some(n) = current {
+            current = side[&n].0.clone();
+            ret.push(n);
+        }
+        ret
+    };
+    let mut path = walk(&sides[0]);
+    path.reverse();
+    path.push(meeting.clone());
+    path.extend(walk(&sides[1]));
+    Some(path)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{astar, bfs, bidirectional, dijkstra};
+
+    // A weighted directed graph: 0 → 1 → 3 is cheaper than the direct 0 → 3
+    fn edges(n: &u32) -> Vec<(u32, u64)> {
+        match n {
+            0 => vec![(1, 2), (2, 5), (3, 10)],
+            1 => vec![(3, 3)],
+            2 => vec![(3, 1)],
+            3 => vec![(4, 1)],
+            _ => vec![],
+        }
+    }
+
+    #[test]
+    fn dijkstra_costs_and_paths() {
+        let s = dijkstra(0, edges, |_| false);
+        assert_eq!(Some(5), s.cost(&3));
+        assert_eq!(Some(6), s.cost(&4));
+        assert_eq!(Some(vec![0, 1, 3, 4]), s.path_to(&4));
+        assert_eq!(Some(vec![0]), s.path_to(&0));
+        assert_eq!(None, s.path_to(&9));
+        assert_eq!(None, s.goal);
+    }
+
+    #[test]
+    fn dijkstra_stops_at_goal() {
+        let s = dijkstra(0, edges, |&n| n == 3);
+        assert_eq!(Some(3), s.goal);
+        assert_eq!(Some(5), s.goal_cost());
+        assert_eq!(Some(vec![0, 1, 3]), s.goal_path());
+        assert!(!s.dist.contains_key(&4));
+    }
+
+    #[test]
+    fn wide_costs() {
+        let big = u64::from(u32::MAX);
+        let s = dijkstra(0u8, |&n| (n < 3).then_some((n + 1, big)), |_| false);
+        assert_eq!(Some(3 * big), s.cost(&3));
+    }
+
+    fn grid_neighbours((x, y): (i32, i32)) -> impl Iterator<Item = (i32, i32)> {
+        [(1, 0), (-1, 0), (0, 1), (0, -1)]
+            .into_iter()
+            .map(move |(dx, dy)| (x + dx, y + dy))
+            .filter(|&(x, y)| (0..10).contains(&x) && (0..10).contains(&y) && (x, y) != (5, 5))
+    }
+
+    #[test]
+    fn astar_matches_dijkstra() {
+        let target = (9, 7);
+        let step = |p: &(i32, i32)| grid_neighbours(*p).map(|n| (n, 1u32));
+        let manhattan =
+            |p: &(i32, i32)| (target.0 - p.0).unsigned_abs() + (target.1 - p.1).unsigned_abs();
+
+        let a = astar((0, 0), step, manhattan, |&p| p == target);
+        let d = dijkstra((0, 0), step, |&p| p == target);
+        assert_eq!(Some(16), a.goal_cost());
+        assert_eq!(d.goal_cost(), a.goal_cost());
+        assert_eq!(17, a.goal_path().unwrap().len());
+        // The heuristic steers the search, so it expands fewer nodes
+        assert!(a.dist.len() < d.dist.len());
+    }
+
+    #[test]
+    fn bfs_and_bidirectional_agree() {
+        let b = bfs((0, 0), |p| grid_neighbours(*p), |_| false);
+        assert_eq!(99, b.dist.len());
+        assert_eq!(Some(18), b.cost(&(9, 9)));
+
+        for target in [(0, 0), (1, 0), (9, 9), (5, 6), (4, 4)] {
+            let path = bidirectional((0, 0), target, |p| grid_neighbours(*p)).unwrap();
+            assert_eq!(b.cost(&target).unwrap() + 1, path.len());
+            assert_eq!((0, 0), path[0]);
+            assert_eq!(target, *path.last().unwrap());
+            for w in path.windows(2) {
+                assert!(grid_neighbours(w[0]).any(|n| n == w[1]));
+            }
+        }
+        assert_eq!(
+            None,
+            bidirectional((0, 0), (20, 20), |p| grid_neighbours(*p))
+        );
+    }
+}
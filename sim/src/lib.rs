@@ -0,0 +1,233 @@
+//! Simulations that advance a generation at a time, and a driver that runs them: for a number of
+//! steps, until they settle, until some condition holds, or until they repeat themselves.
+//!
+//! The driver can be given an observer, which sees every generation as it's made, for logging or
+//! drawing the simulation as it runs.
+
+use std::collections::HashMap;
+use std::hash::Hash;
+
+/// Something that changes a step at a time.
+pub trait Simulation {
+    /// What a step reports about itself, such as how many cells it changed.
+    type Outcome;
+
+    /// Advances by one generation.
+    fn step(&mut self) -> Self::Outcome;
+}
+
+/// Outcomes that tell whether a step changed anything.
+pub trait Settled {
+    fn settled(&self) -> bool;
+}
+
+/// A count of the changes a step made.
+impl Settled for usize {
+    fn settled(&self) -> bool {
+        *self == 0
+    }
+}
+
+/// Whether a step changed anything.
+impl Settled for bool {
+    fn settled(&self) -> bool {
+        !self
+    }
+}
+
+/// Where a simulation starts repeating itself: generation `start + length` is the same as
+/// generation `start`, and so on every `length` generations after.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Cycle {
+    pub start: usize,
+    pub length: usize,
+}
+
+impl Cycle {
+    /// The earliest generation that's the same as `generation`.
+    pub fn earliest(&self, generation: usize) -> usize {
+        if generation < self.start {
+            generation
+        } else {
+            self.start + (generation - self.start) % self.length
+        }
+    }
+}
+
+/// Called with each generation's number, the simulation and what its step reported.
+type Observer<'o, S> = Box<dyn FnMut(usize, &S, &<S as Simulation>::Outcome) + 'o>;
+
+/// Runs a simulation, counting generations from 0 for its starting state.
+pub struct Driver<'o, S: Simulation> {
+    sim: S,
+    generation: usize,
+    observer: Option<Observer<'o, S>>,
+}
+
+impl<'o, S: Simulation> Driver<'o, S> {
+    pub fn new(sim: S) -> Self {
+        Driver {
+            sim,
+            generation: 0,
+            observer: None,
+        }
+    }
+
+    /// Calls `observer` after every step.
+    pub fn observe(mut self, observer: impl FnMut(usize, &S, &S::Outcome) + 'o) -> Self {
+        self.observer = Some(Box::new(observer));
+        self
+    }
+
+    /// The generation the simulation is at: the number of steps taken.
+    pub fn generation(&self) -> usize {
+        self.generation
+    }
+
+    pub fn sim(&self) -> &S {
+        &self.sim
+    }
+
+    pub fn into_sim(self) -> S {
+        self.sim
+    }
+
+    /// Takes one step.
+    pub fn step(&mut self) -> S::Outcome {
+        let outcome = self.sim.step();
+        self.generation += 1;
+        if let Some(observer) = &mut self.observer {
+            observer(self.generation, &self.sim, &outcome);
+        }
+        outcome
+    }
+
+    /// Takes `n` steps, returning what each reported.
+    pub fn run(&mut self, n: usize) -> Vec<S::Outcome> {
+        (0..n).map(|_| self.step()).collect()
+    }
+
+    /// Steps until `done` holds for the new generation and its step's outcome, returning the
+    /// generation it held for.
+    pub fn until(&mut self, mut done: impl FnMut(&S, &S::Outcome) -> bool) -> usize {
+        loop {
+            let outcome = self.step();
+            if done(&self.sim, &outcome) {
+                return self.generation;
+            }
+        }
+    }
+
+    /// Steps until a step changes nothing, returning the generation that step made.
+    pub fn until_fixpoint(&mut self) -> usize
+    where
+        S::Outcome: Settled,
+    {
+        self.until(|_, outcome| outcome.settled())
+    }
+
+    /// Steps until the simulation reaches a state it's been in before, returning the cycle it's
+    /// in. Every state from here on is kept to compare with, so this needs a simulation that
+    /// repeats before it runs out of memory.
+    pub fn find_cycle(&mut self) -> Cycle
+    where
+        S: Clone + Eq + Hash,
+    {
+        let mut seen = HashMap::from([(self.sim.clone(), self.generation)]);
+        loop {
+            self.step();
+            if let Some(&start) = seen.get(&self.sim) {
+                return Cycle {
+                    start,
+                    length: self.generation - start,
+                };
+            }
+            seen.insert(self.sim.clone(), self.generation);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Cycle, Driver, Simulation};
+
+    /// A number that halves when even and goes to 3n + 1 when odd, which ends up cycling
+    /// 4, 2, 1 for every start anyone has tried.
+    #[derive(Clone, Debug, Eq, Hash, PartialEq)]
+    struct Collatz(u64);
+
+    impl Simulation for Collatz {
+        type Outcome = u64;
+
+        fn step(&mut self) -> u64 {
+            self.0 = if self.0.is_multiple_of(2) {
+                self.0 / 2
+            } else {
+                3 * self.0 + 1
+            };
+            self.0
+        }
+    }
+
+    /// Counts down to zero and stays there, reporting whether it moved.
+    struct Countdown(u32);
+
+    impl Simulation for Countdown {
+        type Outcome = bool;
+
+        fn step(&mut self) -> bool {
+            let moved = self.0 > 0;
+            self.0 = self.0.saturating_sub(1);
+            moved
+        }
+    }
+
+    #[test]
+    fn steps_and_predicates() {
+        let mut driver = Driver::new(Collatz(6));
+        assert_eq!(vec![3, 10, 5], driver.run(3));
+        assert_eq!(3, driver.generation());
+        // 16, 8, 4
+        assert_eq!(6, driver.until(|c, _| c.0 == 4));
+        assert_eq!(4, driver.into_sim().0);
+    }
+
+    #[test]
+    fn fixpoints() {
+        let mut driver = Driver::new(Countdown(5));
+        // Five steps count down, and the sixth is the first to change nothing
+        assert_eq!(6, driver.until_fixpoint());
+        assert_eq!(0, driver.sim().0);
+    }
+
+    #[test]
+    fn cycles() {
+        // 6, 3, 10, 5, 16, 8, 4, 2, 1, 4, ...
+        let cycle = Driver::new(Collatz(6)).find_cycle();
+        assert_eq!(
+            Cycle {
+                start: 6,
+                length: 3
+            },
+            cycle
+        );
+        assert_eq!(5, cycle.earliest(5));
+        assert_eq!(6, cycle.earliest(9));
+        assert_eq!(7, cycle.earliest(1000 * 3 + 7));
+    }
+
+    #[test]
+    fn observers_see_every_generation() {
+        let mut seen = vec![];
+        Driver::new(Collatz(3))
+            .observe(|generation, c, &outcome| {
+                assert_eq!(c.0, outcome);
+                seen.push((generation, outcome));
+            })
+            .until(|c, _| c.0 == 1);
+        assert_eq!(
+            vec![(1, 10), (2, 5), (3, 16), (4, 8), (5, 4), (6, 2), (7, 1)],
+            seen
+        );
+    }
+}
@@ -0,0 +1,39 @@
+use anyhow::Result;
+use aoc::Solution;
+
+pub struct Day00;
+
+impl Solution for Day00 {
+    const DAY: u32 = 0;
+    type Input = Vec<String>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Vec<String>> {
+        Ok(aoc::parse_lines(input)?)
+    }
+
+    fn part1(_input: &Vec<String>) -> usize {
+        0
+    }
+
+    fn part2(_input: &Vec<String>) -> usize {
+        0
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::*;
+
+    /// The puzzle's worked example.
+    const EXAMPLE: &str = "";
+
+    #[test]
+    #[ignore = "needs the worked example and its answers"]
+    fn example() {
+        let input = Day00::parse(EXAMPLE).unwrap();
+        assert_eq!(0, Day00::part1(&input));
+        assert_eq!(0, Day00::part2(&input));
+    }
+}
@@ -0,0 +1,88 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use vectory::{Aabb, IntVector, KdTree, Vector};
+
+const CLOUD_SIZE: usize = 100_000;
+
+fn cloud(n: usize, seed: u64) -> Vec<IntVector<3>> {
+    let mut state = seed;
+    let mut next = move || {
+        state ^= state << 13;
+        state ^= state >> 7;
+        state ^= state << 17;
+        (state % 20_001) as i64 - 10_000
+    };
+    (0..n)
+        .map(|_| IntVector::from([next(), next(), next()]))
+        .collect()
+}
+
+fn kdtree_vs_brute_force(c: &mut Criterion) {
+    let points = cloud(CLOUD_SIZE, 0x2545f4914f6cdd1d);
+    let queries = cloud(100, 0x9e3779b97f4a7c15);
+    let tree: KdTree<3> = points.iter().copied().collect();
+
+    c.bench_function("build 100k", |b| {
+        b.iter(|| KdTree::new(black_box(&points).iter().copied()))
+    });
+
+    let mut group = c.benchmark_group("nearest");
+    group.bench_function("kdtree", |b| {
+        b.iter(|| {
+            queries
+                .iter()
+                .filter_map(|q| tree.nearest(q))
+                .copied()
+                .collect::<Vec<_>>()
+        })
+    });
+    group.bench_function("brute force", |b| {
+        b.iter(|| {
+            queries
+                .iter()
+                .filter_map(|q| points.iter().min_by_key(|p| (*p - q).magnitude2()))
+                .copied()
+                .collect::<Vec<_>>()
+        })
+    });
+    group.finish();
+
+    let mut group = c.benchmark_group("manhattan radius 1000");
+    group.bench_function("kdtree", |b| {
+        b.iter(|| {
+            queries
+                .iter()
+                .map(|q| tree.within_manhattan(q, 1000).len())
+                .sum::<usize>()
+        })
+    });
+    group.bench_function("brute force", |b| {
+        b.iter(|| {
+            queries
+                .iter()
+                .map(|q| points.iter().filter(|p| p.manh_dist(q) <= 1000).count())
+                .sum::<usize>()
+        })
+    });
+    group.finish();
+
+    let mut group = c.benchmark_group("box 1000");
+    let boxes: Vec<Aabb<3>> = queries
+        .iter()
+        .map(|q| Aabb::new(*q, q + IntVector::from([1000; 3])))
+        .collect();
+    group.bench_function("kdtree", |b| {
+        b.iter(|| boxes.iter().map(|bx| tree.in_box(bx).len()).sum::<usize>())
+    });
+    group.bench_function("brute force", |b| {
+        b.iter(|| {
+            boxes
+                .iter()
+                .map(|bx| points.iter().filter(|p| bx.contains(p)).count())
+                .sum::<usize>()
+        })
+    });
+    group.finish();
+}
+
+criterion_group!(benches, kdtree_vs_brute_force);
+criterion_main!(benches);
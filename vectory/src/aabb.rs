@@ -0,0 +1,207 @@
+use crate::{IntVector, Scalar};
+#[cfg(feature = "alloc")]
+use alloc::{vec, vec::Vec};
+
+/// An axis-aligned box of lattice points, with both corners inclusive.
+#[derive(Copy, Clone, Eq, PartialEq, Debug, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Aabb<const D: usize, T = i64> {
+    pub min: IntVector<D, T>,
+    pub max: IntVector<D, T>,
+}
+
+impl<const D: usize, T: Scalar> Aabb<D, T> {
+    /// The box spanned by two opposite corners, given in any order.
+    pub fn new(a: IntVector<D, T>, b: IntVector<D, T>) -> Self {
+        Aabb {
+            min: a.min(&b),
+            max: a.max(&b),
+        }
+    }
+
+    /// The smallest box containing every point, or `None` if there are none.
+    pub fn from_points<'a>(points: impl IntoIterator<Item = &'a IntVector<D, T>>) -> Option<Self>
+    where
+        T: 'a,
+    {
+        let mut points = points.into_iter();
+        let first = *points.next()?;
+        let mut ret = Aabb::new(first, first);
+        for p in points {
+            ret.expand_to_include(p);
+        }
+        Some(ret)
+    }
+
+    pub fn contains(&self, p: &IntVector<D, T>) -> bool {
+        (0..D).all(|d| self.min[d] <= p[d] && p[d] <= self.max[d])
+    }
+
+    pub fn contains_box(&self, other: &Self) -> bool {
+        self.contains(&other.min) && self.contains(&other.max)
+    }
+
+    /// Grows the box just enough to cover `p`.
+    pub fn expand_to_include(&mut self, p: &IntVector<D, T>) {
+        self.min = self.min.min(p);
+        self.max = self.max.max(p);
+    }
+
+    pub fn intersection(&self, other: &Self) -> Option<Self> {
+        let min = self.min.max(&other.min);
+        let max = self.max.min(&other.max);
+        if (0..D).all(|d| min[d] <= max[d]) {
+            Some(Aabb { min, max })
+        } else {
+            None
+        }
+    }
+
+    /// The points of `self` not in `other`, as disjoint boxes.
+    ///
+    /// Works an axis at a time: the slabs of `self` below and above `other` along the first axis
+    /// are split off whole, then the remainder is clipped to `other` on that axis and the next
+    /// axis is split the same way.
+    #[cfg(feature = "alloc")]
+    pub fn subtract(&self, other: &Self) -> Vec<Self> {
+        if self.intersection(other).is_none() {
+            return vec![*self];
+        }
+
+        let mut ret = Vec::new();
+        let mut rest = *self;
+        for d in 0..D {
+            if rest.min[d] < other.min[d] {
+                let mut below = rest;
+                below.max[d] = other.min[d] - T::one();
+                ret.push(below);
+                rest.min[d] = other.min[d];
+            }
+            if rest.max[d] > other.max[d] {
+                let mut above = rest;
+                above.min[d] = other.max[d] + T::one();
+                ret.push(above);
+                rest.max[d] = other.max[d];
+            }
+        }
+        ret
+    }
+
+    /// The number of lattice points along each axis.
+    pub fn extent(&self) -> IntVector<D, T> {
+        (self.max - self.min).map(|x| x + T::one())
+    }
+
+    /// The number of lattice points in the box.
+    pub fn volume(&self) -> T {
+        self.extent().iter().fold(T::one(), |acc, &x| acc * x)
+    }
+
+    /// Every lattice point in the box, varying the last axis fastest.
+    pub fn iter(&self) -> Points<D, T> {
+        Points {
+            bounds: *self,
+            next: Some(self.min),
+        }
+    }
+}
+
+impl<const D: usize, T: Scalar> IntoIterator for &Aabb<D, T> {
+    type Item = IntVector<D, T>;
+    type IntoIter = Points<D, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// Iterator over the lattice points of an `Aabb`.
+pub struct Points<const D: usize, T> {
+    bounds: Aabb<D, T>,
+    next: Option<IntVector<D, T>>,
+}
+
+impl<const D: usize, T: Scalar> Iterator for Points<D, T> {
+    type Item = IntVector<D, T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let ret = self.next?;
+
+        // Odometer increment, rolling over into the next axis up
+        let mut p = ret;
+        self.next = None;
+        for d in (0..D).rev() {
+            if p[d] < self.bounds.max[d] {
+                p[d] = p[d] + T::one();
+                self.next = Some(p);
+                break;
+            }
+            p[d] = self.bounds.min[d];
+        }
+
+        Some(ret)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Aabb, IntVector};
+
+    fn cube(lo: i64, hi: i64) -> Aabb<3> {
+        Aabb::new(IntVector::from([lo; 3]), IntVector::from([hi; 3]))
+    }
+
+    #[test]
+    fn construction() {
+        let b = Aabb::new(IntVector::from([3, -1]), IntVector::from([0, 4]));
+        assert_eq!(IntVector::from([0, -1]), b.min);
+        assert_eq!(IntVector::from([3, 4]), b.max);
+        assert_eq!(24, b.volume());
+
+        let points = [[1, 5], [-2, 0], [4, 2]].map(IntVector::from);
+        let b = Aabb::from_points(&points).unwrap();
+        assert_eq!(
+            Aabb::new(IntVector::from([-2, 0]), IntVector::from([4, 5])),
+            b
+        );
+        assert!(points.iter().all(|p| b.contains(p)));
+        assert!(!b.contains(&IntVector::from([5, 0])));
+        assert_eq!(None, Aabb::<2>::from_points(&[]));
+    }
+
+    #[test]
+    fn intersections() {
+        assert_eq!(Some(cube(1, 2)), cube(-1, 2).intersection(&cube(1, 5)));
+        assert_eq!(Some(cube(0, 0)), cube(-1, 0).intersection(&cube(0, 1)));
+        assert_eq!(None, cube(-1, 0).intersection(&cube(1, 2)));
+    }
+
+    #[test]
+    fn subtraction_is_disjoint_and_conserves_volume() {
+        let a = cube(-1, 1);
+        for b in [cube(0, 0), cube(1, 2), cube(-5, 5), cube(2, 3)] {
+            let parts = a.subtract(&b);
+            let isect = a.intersection(&b).map_or(0, |i| i.volume());
+            assert_eq!(
+                a.volume() - isect,
+                parts.iter().map(|p| p.volume()).sum::<i64>()
+            );
+            for (i, p) in parts.iter().enumerate() {
+                assert!(a.contains_box(p));
+                assert_eq!(None, p.intersection(&b));
+                assert!(parts[i + 1..].iter().all(|q| p.intersection(q).is_none()));
+            }
+        }
+    }
+
+    #[test]
+    fn lattice_points() {
+        let b = Aabb::new(IntVector::from([0, 5]), IntVector::from([1, 7]));
+        let points: Vec<_> = b.iter().collect();
+        assert_eq!(6, points.len());
+        assert_eq!(IntVector::from([0, 5]), points[0]);
+        assert_eq!(IntVector::from([0, 6]), points[1]);
+        assert_eq!(IntVector::from([1, 7]), points[5]);
+        assert_eq!(27, cube(-1, 1).iter().count());
+    }
+}
@@ -0,0 +1,122 @@
+use crate::{IntVector, Matrix, Scalar};
+use core::ops::Mul;
+use num::Signed;
+
+/// A linear map followed by a translation: `p ↦ linear · p + translation`.
+///
+/// Composition follows the matrix convention, so `&a * &b` applies `b` first and then `a`.
+#[derive(Clone, Eq, PartialEq, Debug, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Affine<const D: usize, T = i64> {
+    pub linear: Matrix<D, T>,
+    pub translation: IntVector<D, T>,
+}
+
+impl<const D: usize, T: Scalar> Affine<D, T> {
+    pub const fn new(linear: Matrix<D, T>, translation: IntVector<D, T>) -> Self {
+        Affine {
+            linear,
+            translation,
+        }
+    }
+
+    pub fn identity() -> Self {
+        Affine::new(Matrix::identity(), IntVector::zero())
+    }
+
+    pub fn from_linear(linear: Matrix<D, T>) -> Self {
+        Affine::new(linear, IntVector::zero())
+    }
+
+    pub fn from_translation(translation: IntVector<D, T>) -> Self {
+        Affine::new(Matrix::identity(), translation)
+    }
+
+    pub fn apply(&self, p: &IntVector<D, T>) -> IntVector<D, T> {
+        &self.linear * p + self.translation
+    }
+
+    /// Transforms every point of a set, collecting into whatever container the caller wants.
+    pub fn apply_all<'a, I, C>(&self, points: I) -> C
+    where
+        I: IntoIterator<Item = &'a IntVector<D, T>>,
+        C: FromIterator<IntVector<D, T>>,
+        T: 'a,
+    {
+        points.into_iter().map(|p| self.apply(p)).collect()
+    }
+
+    /// The transform that applies `self` and then `next`.
+    pub fn then(&self, next: &Self) -> Self {
+        next * self
+    }
+}
+
+impl<const D: usize, T: Scalar + Signed> Affine<D, T> {
+    /// The transform undoing this one, if the linear part is invertible in `T`.
+    pub fn inverse(&self) -> Option<Self> {
+        let linear = self.linear.inverse()?;
+        let translation = -(&linear * &self.translation);
+        Some(Affine::new(linear, translation))
+    }
+}
+
+impl<const D: usize, T: Scalar> Mul for &Affine<D, T> {
+    type Output = Affine<D, T>;
+
+    fn mul(self, rhs: &Affine<D, T>) -> Self::Output {
+        Affine::new(&self.linear * &rhs.linear, self.apply(&rhs.translation))
+    }
+}
+
+impl<const D: usize, T: Scalar> Mul for Affine<D, T> {
+    type Output = Affine<D, T>;
+
+    fn mul(self, rhs: Affine<D, T>) -> Self::Output {
+        &self * &rhs
+    }
+}
+
+impl<const D: usize, T: Scalar> Mul<&IntVector<D, T>> for &Affine<D, T> {
+    type Output = IntVector<D, T>;
+
+    fn mul(self, rhs: &IntVector<D, T>) -> Self::Output {
+        self.apply(rhs)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Affine, IntVector, Matrix};
+    use std::collections::HashSet;
+
+    #[test]
+    fn apply_and_compose() {
+        let turn = Affine::from_linear(Matrix::rotation(2, 1));
+        let shift = Affine::from_translation(IntVector::from([10, 0, 0]));
+        let p = IntVector::from([1, 0, 0]);
+
+        assert_eq!(IntVector::from([10, 1, 0]), (&shift * &turn).apply(&p));
+        assert_eq!(
+            IntVector::from([-1, 10, 0]),
+            turn.then(&shift).then(&turn).apply(&p)
+        );
+        assert_eq!(shift.then(&turn), &turn * &shift);
+    }
+
+    #[test]
+    fn inverse_round_trip() {
+        let pose = Affine::new(Matrix::rotation(0, 1), IntVector::from([3, -4, 5]));
+        let inv = pose.inverse().unwrap();
+        assert_eq!(Affine::identity(), &pose * &inv);
+        assert_eq!(Affine::identity(), &inv * &pose);
+
+        let points: HashSet<IntVector<3>> = [[1, 2, 3], [-4, 0, 7]]
+            .into_iter()
+            .map(IntVector::from)
+            .collect();
+        let moved: HashSet<_> = pose.apply_all(&points);
+        let back: HashSet<_> = inv.apply_all(&moved);
+        assert_eq!(points, back);
+    }
+}
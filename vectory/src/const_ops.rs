@@ -0,0 +1,254 @@
+//! `const fn` counterparts of the core operations, for the primitive signed integers.
+//!
+//! Trait methods can't be called from a `const fn` on stable Rust, so the generic operators
+//! can't be `const`. These are stamped out per scalar type instead, which lets lookup tables be
+//! built at compile time:
+//!
+//! ```
+//! use vectory::Matrix;
+//!
+//! const ROTATIONS: [Matrix<3>; 24] = Matrix::<3>::orientation_table(false);
+//! assert_eq!(Matrix::<3>::IDENTITY, ROTATIONS[0]);
+//! ```
+
+use crate::{IntVector, Matrix};
+
+/// `[0, 1, …, D-1]`, the first permutation in lexicographic order.
+pub(crate) const fn first_permutation<const D: usize>() -> [usize; D] {
+    let mut perm = [0; D];
+    let mut i = 0;
+    while i < D {
+        perm[i] = i;
+        i += 1;
+    }
+    perm
+}
+
+/// Steps `perm` to the next permutation in lexicographic order; false if it was the last.
+pub(crate) const fn next_permutation<const D: usize>(perm: &mut [usize; D]) -> bool {
+    if D < 2 {
+        return false;
+    }
+    let mut i = D - 1;
+    while i > 0 && perm[i - 1] >= perm[i] {
+        i -= 1;
+    }
+    if i == 0 {
+        return false;
+    }
+    let mut j = D - 1;
+    while perm[j] <= perm[i - 1] {
+        j -= 1;
+    }
+    let tmp = perm[i - 1];
+    perm[i - 1] = perm[j];
+    perm[j] = tmp;
+
+    // The suffix after i-1 is descending; reversing it makes it the smallest arrangement
+    let (mut lo, mut hi) = (i, D - 1);
+    while lo < hi {
+        let tmp = perm[lo];
+        perm[lo] = perm[hi];
+        perm[hi] = tmp;
+        lo += 1;
+        hi -= 1;
+    }
+    true
+}
+
+/// The number of out-of-order pairs in `perm`; its parity is the permutation's sign.
+pub(crate) const fn inversions<const D: usize>(perm: &[usize; D]) -> usize {
+    let mut n = 0;
+    let mut i = 0;
+    while i < D {
+        let mut j = i + 1;
+        while j < D {
+            if perm[i] > perm[j] {
+                n += 1;
+            }
+            j += 1;
+        }
+        i += 1;
+    }
+    n
+}
+
+macro_rules! impl_const_ops {
+    ($($t:ty),*) => {$(
+        impl<const D: usize> IntVector<D, $t> {
+            pub const ZERO: Self = IntVector { xs: [0; D] };
+
+            pub const fn const_add(self, rhs: Self) -> Self {
+                let mut xs = self.xs;
+                let mut i = 0;
+                while i < D {
+                    xs[i] += rhs.xs[i];
+                    i += 1;
+                }
+                IntVector { xs }
+            }
+
+            pub const fn const_sub(self, rhs: Self) -> Self {
+                let mut xs = self.xs;
+                let mut i = 0;
+                while i < D {
+                    xs[i] -= rhs.xs[i];
+                    i += 1;
+                }
+                IntVector { xs }
+            }
+
+            pub const fn const_neg(self) -> Self {
+                let mut xs = self.xs;
+                let mut i = 0;
+                while i < D {
+                    xs[i] = -xs[i];
+                    i += 1;
+                }
+                IntVector { xs }
+            }
+
+            pub const fn const_dot(&self, rhs: &Self) -> $t {
+                let mut acc = 0;
+                let mut i = 0;
+                while i < D {
+                    acc += self.xs[i] * rhs.xs[i];
+                    i += 1;
+                }
+                acc
+            }
+        }
+
+        impl<const D: usize> Matrix<D, $t> {
+            pub const IDENTITY: Self = {
+                let mut xs = [[0; D]; D];
+                let mut i = 0;
+                while i < D {
+                    xs[i][i] = 1;
+                    i += 1;
+                }
+                Matrix { xs }
+            };
+
+            pub const fn const_transpose(&self) -> Self {
+                let mut xs = self.xs;
+                let mut i = 0;
+                while i < D {
+                    let mut j = 0;
+                    while j < D {
+                        xs[i][j] = self.xs[j][i];
+                        j += 1;
+                    }
+                    i += 1;
+                }
+                Matrix { xs }
+            }
+
+            pub const fn const_mul(&self, rhs: &Self) -> Self {
+                let mut xs = [[0; D]; D];
+                let mut i = 0;
+                while i < D {
+                    let mut j = 0;
+                    while j < D {
+                        let mut k = 0;
+                        while k < D {
+                            xs[i][j] += self.xs[i][k] * rhs.xs[k][j];
+                            k += 1;
+                        }
+                        j += 1;
+                    }
+                    i += 1;
+                }
+                Matrix { xs }
+            }
+
+            pub const fn const_apply(&self, v: &IntVector<D, $t>) -> IntVector<D, $t> {
+                let mut xs = [0; D];
+                let mut i = 0;
+                while i < D {
+                    let mut j = 0;
+                    while j < D {
+                        xs[i] += self.xs[i][j] * v.xs[j];
+                        j += 1;
+                    }
+                    i += 1;
+                }
+                IntVector { xs }
+            }
+
+            /// The signed permutation matrices, in the same order as `orientations`.
+            ///
+            /// `N` must be the number produced: D!·2^(D-1) proper rotations, or D!·2^D with
+            /// `mirror`. Anything else fails to compile when evaluated in a `const` item.
+            pub const fn orientation_table<const N: usize>(mirror: bool) -> [Self; N] {
+                let mut ret = [Self::IDENTITY; N];
+                let mut n = 0;
+                let mut perm = first_permutation::<D>();
+                loop {
+                    let odd = inversions(&perm) % 2 == 1;
+                    let mut signs: u32 = 0;
+                    while signs < (1 << D) {
+                        let flipped = signs.count_ones() % 2 == 1;
+                        if mirror || odd == flipped {
+                            assert!(n < N, "more orientations than the table holds");
+                            let mut xs = [[0; D]; D];
+                            let mut col = 0;
+                            while col < D {
+                                xs[perm[col]][col] = if signs & (1 << col) == 0 { 1 } else { -1 };
+                                col += 1;
+                            }
+                            ret[n] = Matrix { xs };
+                            n += 1;
+                        }
+                        signs += 1;
+                    }
+                    if !next_permutation(&mut perm) {
+                        break;
+                    }
+                }
+                assert!(n == N, "fewer orientations than the table holds");
+                ret
+            }
+        }
+    )*};
+}
+
+impl_const_ops!(i8, i16, i32, i64, i128, isize);
+
+#[cfg(test)]
+mod tests {
+    use crate::{IntVector, Matrix};
+
+    const TURN_Z: Matrix<3> = Matrix {
+        xs: [[0, -1, 0], [1, 0, 0], [0, 0, 1]],
+    };
+    const HALF_TURN: Matrix<3> = TURN_Z.const_mul(&TURN_Z);
+    const ROTATIONS: [Matrix<3>; 24] = Matrix::<3>::orientation_table(false);
+    const SQUARE: [Matrix<2, i8>; 8] = Matrix::<2, i8>::orientation_table(true);
+
+    #[test]
+    fn tables_match_runtime_orientations() {
+        assert_eq!(Matrix::orientations(false), ROTATIONS.to_vec());
+        assert_eq!(Matrix::orientations(true), SQUARE.to_vec());
+        assert_eq!(
+            Matrix::<4>::orientations(false),
+            Matrix::<4>::orientation_table::<192>(false).to_vec()
+        );
+    }
+
+    #[test]
+    fn const_arithmetic_matches_operators() {
+        assert_eq!(&TURN_Z * &TURN_Z, HALF_TURN);
+        assert_eq!(TURN_Z.transpose(), TURN_Z.const_transpose());
+        assert_eq!(Matrix::identity(), Matrix::<3>::IDENTITY);
+
+        const V: IntVector<3> = IntVector::new([1, 2, 3]);
+        const W: IntVector<3> = HALF_TURN.const_apply(&V);
+        assert_eq!(IntVector::from([-1, -2, 3]), W);
+        assert_eq!(V + W, V.const_add(W));
+        assert_eq!(V - W, V.const_sub(W));
+        assert_eq!(-V, V.const_neg());
+        assert_eq!(V.dot(&W), V.const_dot(&W));
+        assert_eq!(IntVector::zero(), IntVector::<3, i32>::ZERO);
+    }
+}
@@ -0,0 +1,312 @@
+use crate::{abs_diff, Aabb, IntVector, Scalar};
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+
+/// A static k-d tree over a set of points.
+///
+/// The tree is implicit: the points are stored in one array, arranged so that the middle element
+/// of every sub-slice splits the rest on the axis for that depth, with smaller coordinates to its
+/// left. Bulk-load it with `collect()` or `KdTree::new`.
+///
+/// Unsigned scalars work too, as distances are built from absolute differences. Points with a NaN
+/// coordinate are left out, and queries from one find nothing.
+#[derive(Clone, Debug)]
+pub struct KdTree<const D: usize, T = i64> {
+    points: Vec<IntVector<D, T>>,
+}
+
+impl<const D: usize, T: Scalar> KdTree<D, T> {
+    pub fn new(points: impl IntoIterator<Item = IntVector<D, T>>) -> Self {
+        let mut points: Vec<_> = points.into_iter().filter(comparable).collect();
+        Self::arrange(&mut points, 0);
+        KdTree { points }
+    }
+
+    fn arrange(points: &mut [IntVector<D, T>], depth: usize) {
+        if points.len() <= 1 {
+            return;
+        }
+        let axis = depth % D;
+        let mid = points.len() / 2;
+        // Every coordinate is comparable, as NaNs were left out
+        points.select_nth_unstable_by(mid, |a, b| {
+            a[axis].partial_cmp(&b[axis]).unwrap_or(Ordering::Equal)
+        });
+        let (left, right) = points.split_at_mut(mid);
+        Self::arrange(left, depth + 1);
+        Self::arrange(&mut right[1..], depth + 1);
+    }
+
+    pub fn len(&self) -> usize {
+        self.points.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.points.is_empty()
+    }
+
+    pub fn iter(&self) -> core::slice::Iter<'_, IntVector<D, T>> {
+        self.points.iter()
+    }
+
+    /// The point closest to `q` by Euclidean distance; ties go to whichever is found first.
+    ///
+    /// Squared distances to the points searched have to fit in `T`.
+    pub fn nearest(&self, q: &IntVector<D, T>) -> Option<&IntVector<D, T>> {
+        if !comparable(q) {
+            return None;
+        }
+        let mut best = None;
+        Self::nearest_in(&self.points, 0, q, &mut best);
+        best.map(|(p, _)| p)
+    }
+
+    fn nearest_in<'a>(
+        points: &'a [IntVector<D, T>],
+        depth: usize,
+        q: &IntVector<D, T>,
+        best: &mut Option<(&'a IntVector<D, T>, T)>,
+    ) {
+        if points.is_empty() {
+            return;
+        }
+        let axis = depth % D;
+        let mid = points.len() / 2;
+        let p = &points[mid];
+
+        let d2 = distance2(p, q);
+        if best.is_none_or(|(_, b)| d2 < b) {
+            *best = Some((p, d2));
+        }
+
+        let (near, far) = if q[axis] < p[axis] {
+            (&points[..mid], &points[mid + 1..])
+        } else {
+            (&points[mid + 1..], &points[..mid])
+        };
+        Self::nearest_in(near, depth + 1, q, best);
+
+        // The far side can only help if the splitting plane is closer than the best so far
+        let plane = abs_diff(q[axis], p[axis]);
+        if best.is_none_or(|(_, b)| plane * plane < b) {
+            Self::nearest_in(far, depth + 1, q, best);
+        }
+    }
+
+    /// Every point within Manhattan distance `r` of `q`, inclusive.
+    pub fn within_manhattan(&self, q: &IntVector<D, T>, r: T) -> Vec<&IntVector<D, T>> {
+        let mut ret = Vec::new();
+        if !comparable(q) {
+            return ret;
+        }
+        Self::within_in(&self.points, 0, q, r, &|p| fits(p, q, r, Some), &mut ret);
+        ret
+    }
+
+    /// Every point within Euclidean distance `r` of `q`, inclusive.
+    pub fn within_euclidean(&self, q: &IntVector<D, T>, r: T) -> Vec<&IntVector<D, T>> {
+        let mut ret = Vec::new();
+        if !comparable(q) {
+            return ret;
+        }
+        let square = |d: T| (d <= r).then(|| d * d);
+        Self::within_in(
+            &self.points,
+            0,
+            q,
+            r,
+            &|p| fits(p, q, r * r, square),
+            &mut ret,
+        );
+        ret
+    }
+
+    /// Visits the subtrees that may hold points within `r` of `q` along every single axis,
+    /// keeping those that pass `accept`. Both distances are bounded below by the axis distance.
+    fn within_in<'a>(
+        points: &'a [IntVector<D, T>],
+        depth: usize,
+        q: &IntVector<D, T>,
+        r: T,
+        accept: &impl Fn(&IntVector<D, T>) -> bool,
+        ret: &mut Vec<&'a IntVector<D, T>>,
+    ) {
+        if points.is_empty() {
+            return;
+        }
+        let axis = depth % D;
+        let mid = points.len() / 2;
+        let p = &points[mid];
+
+        if accept(p) {
+            ret.push(p);
+        }
+        // Compared by how far apart they are, rather than by adding `r`, which could overflow
+        let close = abs_diff(q[axis], p[axis]) <= r;
+        if q[axis] <= p[axis] || close {
+            Self::within_in(&points[..mid], depth + 1, q, r, accept, ret);
+        }
+        if p[axis] <= q[axis] || close {
+            Self::within_in(&points[mid + 1..], depth + 1, q, r, accept, ret);
+        }
+    }
+
+    /// Every point inside `bounds`.
+    pub fn in_box(&self, bounds: &Aabb<D, T>) -> Vec<&IntVector<D, T>> {
+        let mut ret = Vec::new();
+        Self::in_box_in(&self.points, 0, bounds, &mut ret);
+        ret
+    }
+
+    fn in_box_in<'a>(
+        points: &'a [IntVector<D, T>],
+        depth: usize,
+        bounds: &Aabb<D, T>,
+        ret: &mut Vec<&'a IntVector<D, T>>,
+    ) {
+        if points.is_empty() {
+            return;
+        }
+        let axis = depth % D;
+        let mid = points.len() / 2;
+        let p = &points[mid];
+
+        if bounds.contains(p) {
+            ret.push(p);
+        }
+        if bounds.min[axis] <= p[axis] {
+            Self::in_box_in(&points[..mid], depth + 1, bounds, ret);
+        }
+        if p[axis] <= bounds.max[axis] {
+            Self::in_box_in(&points[mid + 1..], depth + 1, bounds, ret);
+        }
+    }
+}
+
+/// Whether every coordinate of `p` can be ordered, which is all but a float NaN.
+fn comparable<const D: usize, T: Scalar>(p: &IntVector<D, T>) -> bool {
+    (0..D).all(|i| p[i].partial_cmp(&p[i]).is_some())
+}
+
+/// Whether the `cost` of the differences between `p` and `q` along every axis adds up to no more
+/// than `budget`. Stopping as soon as it's spent means far-off points can't overflow the sum.
+fn fits<const D: usize, T: Scalar>(
+    p: &IntVector<D, T>,
+    q: &IntVector<D, T>,
+    mut budget: T,
+    cost: impl Fn(T) -> Option<T>,
+) -> bool {
+    (0..D).all(|i| match cost(abs_diff(p[i], q[i])) {
+        Some(c) if c <= budget => {
+            budget = budget - c;
+            true
+        }
+        _ => false,
+    })
+}
+
+/// The squared Euclidean distance between `p` and `q`.
+fn distance2<const D: usize, T: Scalar>(p: &IntVector<D, T>, q: &IntVector<D, T>) -> T {
+    (0..D)
+        .map(|i| abs_diff(p[i], q[i]))
+        .fold(T::zero(), |acc, d| acc + d * d)
+}
+
+impl<const D: usize, T: Scalar> FromIterator<IntVector<D, T>> for KdTree<D, T> {
+    fn from_iter<I: IntoIterator<Item = IntVector<D, T>>>(iter: I) -> Self {
+        KdTree::new(iter)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Aabb, IntVector, KdTree, Vector};
+
+    // Small deterministic point cloud, so failures are reproducible
+    fn cloud(n: usize, seed: u64) -> Vec<IntVector<3>> {
+        let mut state = seed;
+        let mut next = move || {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            (state % 201) as i64 - 100
+        };
+        (0..n)
+            .map(|_| IntVector::from([next(), next(), next()]))
+            .collect()
+    }
+
+    fn sorted(mut points: Vec<&IntVector<3>>) -> Vec<&IntVector<3>> {
+        points.sort_by_key(|p| (p[0], p[1], p[2]));
+        points
+    }
+
+    #[test]
+    fn matches_brute_force() {
+        let points = cloud(2000, 0x2545f4914f6cdd1d);
+        let tree: KdTree<3> = points.iter().copied().collect();
+        assert_eq!(points.len(), tree.len());
+
+        for q in cloud(50, 0x9e3779b97f4a7c15) {
+            let best = tree.nearest(&q).unwrap();
+            let brute = points.iter().map(|p| (p - q).magnitude2()).min().unwrap();
+            assert_eq!(brute, (best - q).magnitude2());
+
+            let expected = points.iter().filter(|p| p.manh_dist(&q) <= 30).collect();
+            assert_eq!(sorted(expected), sorted(tree.within_manhattan(&q, 30)));
+
+            let expected = points
+                .iter()
+                .filter(|p| (*p - q).magnitude2() <= 400)
+                .collect();
+            assert_eq!(sorted(expected), sorted(tree.within_euclidean(&q, 20)));
+
+            let bounds = Aabb::new(q, q + IntVector::from([25, -10, 40]));
+            let expected = points.iter().filter(|p| bounds.contains(p)).collect();
+            assert_eq!(sorted(expected), sorted(tree.in_box(&bounds)));
+        }
+    }
+
+    #[test]
+    fn unsigned_scalars() {
+        let points: Vec<IntVector<2, u32>> = [[0, 0], [3, 1], [8, 9], [u32::MAX, u32::MAX - 2]]
+            .into_iter()
+            .map(IntVector::from)
+            .collect();
+        // Squared distances to the far corner don't fit, so it's only in the radius queries
+        let near: KdTree<2, u32> = points[..3].iter().copied().collect();
+        assert_eq!(Some(&points[1]), near.nearest(&IntVector::from([2, 0])));
+        assert_eq!(Some(&points[0]), near.nearest(&IntVector::from([0, 1])));
+
+        let tree: KdTree<2, u32> = points.iter().copied().collect();
+        let corner = IntVector::from([u32::MAX - 1, u32::MAX]);
+        assert_eq!(vec![&points[3]], tree.within_manhattan(&corner, 10));
+        assert_eq!(vec![&points[3]], tree.within_euclidean(&corner, 3));
+        assert!(tree.within_euclidean(&corner, 2).is_empty());
+        let origin = IntVector::from([1, 0]);
+        assert_eq!(vec![&points[0]], tree.within_manhattan(&origin, 2));
+        assert_eq!(vec![&points[0]], tree.within_euclidean(&origin, 1));
+    }
+
+    #[test]
+    fn float_nans_are_left_out() {
+        let points = [[0.0, 0.0], [f64::NAN, 1.0], [2.0, 2.0]].map(IntVector::<2, f64>::from);
+        let tree: KdTree<2, f64> = points.iter().copied().collect();
+        assert_eq!(2, tree.len());
+        assert_eq!(Some(&points[2]), tree.nearest(&IntVector::from([1.5, 1.0])));
+        assert_eq!(2, tree.within_manhattan(&IntVector::zero(), 4.0).len());
+
+        let nan = IntVector::from([f64::NAN, 0.0]);
+        assert_eq!(None, tree.nearest(&nan));
+        assert!(tree.within_manhattan(&nan, 10.0).is_empty());
+        assert!(tree.within_euclidean(&nan, 10.0).is_empty());
+    }
+
+    #[test]
+    fn empty_tree() {
+        let tree: KdTree<2> = KdTree::new(vec![]);
+        assert!(tree.is_empty());
+        assert_eq!(None, tree.nearest(&IntVector::zero()));
+        assert!(tree.within_manhattan(&IntVector::zero(), 5).is_empty());
+    }
+}
@@ -1,76 +1,410 @@
-use itertools::Itertools;
-use std::fmt::{Display, Formatter};
-use std::ops::{Add, Index, Mul, Sub};
+//! Small fixed-size integer vectors and matrices.
+//!
+//! The crate is `no_std`. The `alloc` feature adds everything that returns a collection, and
+//! `std` (on by default) adds the floating-point distances and point-cloud registration.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+#[cfg(feature = "alloc")]
+use alloc::{string::String, vec::Vec};
+use core::fmt::{Display, Formatter};
+use core::ops::{Add, AddAssign, Div, Index, IndexMut, Mul, Neg, Sub, SubAssign};
+#[cfg(feature = "alloc")]
+use core::str::FromStr;
+use num::rational::Ratio;
+#[cfg(feature = "std")]
+use num::Float;
+use num::{Integer, Num, Signed};
+
+mod aabb;
+mod affine;
+mod const_ops;
+#[cfg(feature = "alloc")]
+mod kdtree;
+#[cfg(feature = "std")]
+pub mod registration;
+mod segment;
+#[cfg(feature = "serde")]
+mod serialize;
+
+pub use aabb::{Aabb, Points};
+pub use affine::Affine;
+#[cfg(feature = "alloc")]
+pub use kdtree::KdTree;
+pub use segment::{Intersection, Segment};
+
+/// Scalar types usable as vector and matrix components.
+///
+/// Anything that behaves like a number (`num::Num`) and can be copied and ordered qualifies:
+/// the primitive integers and floats, as well as `num::Rational64`.
+pub trait Scalar: Num + Copy + PartialOrd {}
+
+impl<T: Num + Copy + PartialOrd> Scalar for T {}
 
 #[derive(Copy, Clone, Eq, PartialEq, Debug, Hash)]
-pub struct IntVector<const D: usize> {
-    xs: [i64; D],
+pub struct IntVector<const D: usize, T = i64> {
+    xs: [T; D],
+}
+
+impl<const D: usize, T> IntVector<D, T> {
+    pub const fn new(xs: [T; D]) -> Self {
+        IntVector { xs }
+    }
+}
+
+impl<const D: usize, T: Scalar> IntVector<D, T> {
+    /// The vector with every component zero.
+    pub fn zero() -> Self {
+        IntVector { xs: [T::zero(); D] }
+    }
+
+    /// The unit vector along `axis`.
+    pub fn unit(axis: usize) -> Self {
+        let mut ret = Self::zero();
+        ret.xs[axis] = T::one();
+        ret
+    }
+
+    /// Converts each component with `f`, e.g. to change the scalar type.
+    pub fn map<U: Scalar>(&self, f: impl Fn(T) -> U) -> IntVector<D, U> {
+        IntVector { xs: self.xs.map(f) }
+    }
+
+    fn zip_with(&self, rhs: &Self, f: impl Fn(T, T) -> T) -> Self {
+        let mut ret = *self;
+        for i in 0..D {
+            ret.xs[i] = f(self.xs[i], rhs.xs[i]);
+        }
+        ret
+    }
+
+    pub fn iter(&self) -> core::slice::Iter<'_, T> {
+        self.xs.iter()
+    }
+
+    pub fn dot(&self, rhs: &Self) -> T {
+        self.iter()
+            .zip(rhs.iter())
+            .fold(T::zero(), |acc, (&a, &b)| acc + a * b)
+    }
+
+    /// Component-wise minimum.
+    pub fn min(&self, rhs: &Self) -> Self {
+        self.zip_with(rhs, |a, b| if b < a { b } else { a })
+    }
+
+    /// Component-wise maximum.
+    pub fn max(&self, rhs: &Self) -> Self {
+        self.zip_with(rhs, |a, b| if b > a { b } else { a })
+    }
+}
+
+impl<const D: usize, T: Scalar + Signed> IntVector<D, T> {
+    /// Component-wise absolute value.
+    pub fn abs(&self) -> Self {
+        self.map(|x| x.abs())
+    }
+
+    /// Component-wise sign: each component becomes -1, 0 or 1.
+    pub fn signum(&self) -> Self {
+        self.map(|x| x.signum())
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<const D: usize, T: Scalar + Signed> IntVector<D, T> {
+    /// Every nonzero offset within Chebyshev distance `radius`: the Moore neighbourhood.
+    pub fn moore_offsets(radius: T) -> Vec<Self> {
+        Aabb::new(IntVector::from([-radius; D]), IntVector::from([radius; D]))
+            .iter()
+            .filter(|o| *o != Self::zero())
+            .collect()
+    }
+
+    /// Every nonzero offset within Manhattan distance `radius`: the von Neumann neighbourhood.
+    pub fn von_neumann_offsets(radius: T) -> Vec<Self> {
+        Self::moore_offsets(radius)
+            .into_iter()
+            .filter(|o| o.manh_dist(&Self::zero()) <= radius)
+            .collect()
+    }
+
+    /// The points of the Moore neighbourhood around `self`.
+    pub fn moore(&self, radius: T) -> impl Iterator<Item = Self> {
+        let center = *self;
+        Self::moore_offsets(radius)
+            .into_iter()
+            .map(move |o| center + o)
+    }
+
+    /// The points of the von Neumann neighbourhood around `self`.
+    pub fn von_neumann(&self, radius: T) -> impl Iterator<Item = Self> {
+        let center = *self;
+        Self::von_neumann_offsets(radius)
+            .into_iter()
+            .map(move |o| center + o)
+    }
+}
+
+impl<T: Scalar> IntVector<3, T> {
+    pub fn cross(&self, rhs: &Self) -> Self {
+        let [a0, a1, a2] = self.xs;
+        let [b0, b1, b2] = rhs.xs;
+        IntVector {
+            xs: [a1 * b2 - a2 * b1, a2 * b0 - a0 * b2, a0 * b1 - a1 * b0],
+        }
+    }
+}
+
+/// Writes `[a,b,c]` without allocating.
+fn write_list<T: Display>(f: &mut Formatter<'_>, xs: &[T]) -> core::fmt::Result {
+    f.write_str("[")?;
+    for (i, x) in xs.iter().enumerate() {
+        if i > 0 {
+            f.write_str(",")?;
+        }
+        write!(f, "{}", x)?;
+    }
+    f.write_str("]")
+}
+
+impl<const D: usize, T: Display> Display for IntVector<D, T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write_list(f, &self.xs)
+    }
+}
+
+#[cfg(feature = "alloc")]
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub enum ParseVectorError {
+    WrongLength { expected: usize, found: usize },
+    BadComponent { index: usize, token: String },
+}
+
+#[cfg(feature = "alloc")]
+impl Display for ParseVectorError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            ParseVectorError::WrongLength { expected, found } => {
+                write!(f, "expected {} components, found {}", expected, found)
+            }
+            ParseVectorError::BadComponent { index, token } => {
+                write!(f, "component {} is not a number: {:?}", index, token)
+            }
+        }
+    }
 }
 
-impl<const D: usize> Display for IntVector<D> {
-    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        let s = self.xs.iter().map(|n| n.to_string()).join(",");
-        write!(f, "[{}]", s)
+#[cfg(feature = "alloc")]
+impl core::error::Error for ParseVectorError {}
+
+/// Parses the `[1,2,3]` form written by `Display`, or the bare `1,2,3` form used in puzzle input.
+#[cfg(feature = "alloc")]
+impl<const D: usize, T: Scalar + FromStr> FromStr for IntVector<D, T> {
+    type Err = ParseVectorError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        let s = s
+            .strip_prefix('[')
+            .and_then(|s| s.strip_suffix(']'))
+            .unwrap_or(s);
+
+        let tokens: Vec<&str> = s.split(',').map(str::trim).collect();
+        if tokens.len() != D {
+            return Err(ParseVectorError::WrongLength {
+                expected: D,
+                found: tokens.len(),
+            });
+        }
+
+        let mut ret = Self::zero();
+        for (index, token) in tokens.into_iter().enumerate() {
+            ret.xs[index] = token.parse().map_err(|_| ParseVectorError::BadComponent {
+                index,
+                token: String::from(token),
+            })?;
+        }
+        Ok(ret)
     }
 }
 
-impl<const D: usize> From<[i64; D]> for IntVector<D> {
-    fn from(src: [i64; D]) -> Self {
+impl<const D: usize, T> From<[T; D]> for IntVector<D, T> {
+    fn from(src: [T; D]) -> Self {
         IntVector { xs: src }
     }
 }
 
-impl<const D: usize> From<&[i64]> for IntVector<D> {
-    fn from(src: &[i64]) -> Self {
-        let mut xs = [0; D];
+impl<const D: usize, T: Scalar> From<&[T]> for IntVector<D, T> {
+    fn from(src: &[T]) -> Self {
+        let mut xs = [T::zero(); D];
         xs.copy_from_slice(src);
         IntVector { xs }
     }
 }
 
-impl<const D: usize> Index<usize> for IntVector<D> {
-    type Output = i64;
+impl<const D: usize, T> IntoIterator for IntVector<D, T> {
+    type Item = T;
+    type IntoIter = core::array::IntoIter<T, D>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.xs.into_iter()
+    }
+}
+
+impl<'a, const D: usize, T> IntoIterator for &'a IntVector<D, T> {
+    type Item = &'a T;
+    type IntoIter = core::slice::Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.xs.iter()
+    }
+}
+
+impl<const D: usize, T> Index<usize> for IntVector<D, T> {
+    type Output = T;
 
     fn index(&self, index: usize) -> &Self::Output {
         Index::index(&self.xs, index)
     }
 }
 
-impl<const D: usize> Add for &IntVector<D> {
-    type Output = IntVector<D>;
+impl<const D: usize, T> IndexMut<usize> for IntVector<D, T> {
+    fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+        IndexMut::index_mut(&mut self.xs, index)
+    }
+}
+
+impl<const D: usize, T: Scalar> Add for &IntVector<D, T> {
+    type Output = IntVector<D, T>;
 
     fn add(self, rhs: Self) -> Self::Output {
-        let mut ret = IntVector { xs: [0; D] };
-        for i in 0..D {
-            ret.xs[i] = self[i] + rhs[i];
-        }
-        ret
+        self.zip_with(rhs, |a, b| a + b)
     }
 }
 
-impl<const D: usize> Sub for &IntVector<D> {
-    type Output = IntVector<D>;
+impl<const D: usize, T: Scalar> Sub for &IntVector<D, T> {
+    type Output = IntVector<D, T>;
 
     fn sub(self, rhs: Self) -> Self::Output {
-        let mut ret = IntVector { xs: [0; D] };
-        for i in 0..D {
-            ret.xs[i] = self.xs[i] - rhs.xs[i];
+        self.zip_with(rhs, |a, b| a - b)
+    }
+}
+
+impl<const D: usize, T: Scalar + Neg<Output = T>> Neg for &IntVector<D, T> {
+    type Output = IntVector<D, T>;
+
+    fn neg(self) -> Self::Output {
+        self.map(|x| -x)
+    }
+}
+
+impl<const D: usize, T: Scalar + Neg<Output = T>> Neg for IntVector<D, T> {
+    type Output = IntVector<D, T>;
+
+    fn neg(self) -> Self::Output {
+        -&self
+    }
+}
+
+impl<const D: usize, T: Scalar> Mul<T> for &IntVector<D, T> {
+    type Output = IntVector<D, T>;
+
+    fn mul(self, rhs: T) -> Self::Output {
+        self.map(|x| x * rhs)
+    }
+}
+
+impl<const D: usize, T: Scalar> Div<T> for &IntVector<D, T> {
+    type Output = IntVector<D, T>;
+
+    fn div(self, rhs: T) -> Self::Output {
+        self.map(|x| x / rhs)
+    }
+}
+
+/// Derives the owned and mixed-reference forms of a binary operator from its `&a op &b` impl.
+macro_rules! forward_binop {
+    ($imp:ident, $method:ident) => {
+        impl<const D: usize, T: Scalar> $imp for IntVector<D, T> {
+            type Output = IntVector<D, T>;
+
+            fn $method(self, rhs: Self) -> Self::Output {
+                $imp::$method(&self, &rhs)
+            }
         }
 
-        ret
+        impl<const D: usize, T: Scalar> $imp<&IntVector<D, T>> for IntVector<D, T> {
+            type Output = IntVector<D, T>;
+
+            fn $method(self, rhs: &Self) -> Self::Output {
+                $imp::$method(&self, rhs)
+            }
+        }
+
+        impl<const D: usize, T: Scalar> $imp<IntVector<D, T>> for &IntVector<D, T> {
+            type Output = IntVector<D, T>;
+
+            fn $method(self, rhs: IntVector<D, T>) -> Self::Output {
+                $imp::$method(self, &rhs)
+            }
+        }
+    };
+}
+
+forward_binop!(Add, add);
+forward_binop!(Sub, sub);
+
+/// Derives the owned form of a vector-by-scalar operator from its `&v op k` impl.
+macro_rules! forward_scalar_op {
+    ($imp:ident, $method:ident) => {
+        impl<const D: usize, T: Scalar> $imp<T> for IntVector<D, T> {
+            type Output = IntVector<D, T>;
+
+            fn $method(self, rhs: T) -> Self::Output {
+                $imp::$method(&self, rhs)
+            }
+        }
+    };
+}
+
+forward_scalar_op!(Mul, mul);
+forward_scalar_op!(Div, div);
+
+impl<const D: usize, T: Scalar> AddAssign<&IntVector<D, T>> for IntVector<D, T> {
+    fn add_assign(&mut self, rhs: &IntVector<D, T>) {
+        *self = self.zip_with(rhs, |a, b| a + b);
+    }
+}
+
+impl<const D: usize, T: Scalar> AddAssign for IntVector<D, T> {
+    fn add_assign(&mut self, rhs: IntVector<D, T>) {
+        *self += &rhs;
+    }
+}
+
+impl<const D: usize, T: Scalar> SubAssign<&IntVector<D, T>> for IntVector<D, T> {
+    fn sub_assign(&mut self, rhs: &IntVector<D, T>) {
+        *self = self.zip_with(rhs, |a, b| a - b);
     }
 }
 
-pub trait Vector<T>
-where
-    T: Default + Add<Output = T> + Mul<Output = T> + Sub<Output = T> + PartialOrd<T> + Copy,
-{
+impl<const D: usize, T: Scalar> SubAssign for IntVector<D, T> {
+    fn sub_assign(&mut self, rhs: IntVector<D, T>) {
+        *self -= &rhs;
+    }
+}
+
+pub trait Vector<T: Scalar> {
     const DIMS: usize;
 
     fn at(&self, d: usize) -> T;
 
     fn magnitude2(&self) -> T {
-        let mut acc = T::default();
+        let mut acc = T::zero();
         for i in 0..Self::DIMS {
             let v = self.at(i);
             acc = acc + v * v;
@@ -79,7 +413,7 @@ where
     }
 
     fn manh_dist(&self, other: &Self) -> T {
-        let mut acc = T::default();
+        let mut acc = T::zero();
         for i in 0..Self::DIMS {
             let a = self.at(i);
             let b = other.at(i);
@@ -91,24 +425,271 @@ where
         }
         acc
     }
+
+    /// The largest difference along any one axis (the L∞ distance).
+    fn cheb_dist(&self, other: &Self) -> T {
+        let mut acc = T::zero();
+        for i in 0..Self::DIMS {
+            let d = abs_diff(self.at(i), other.at(i));
+            if d > acc {
+                acc = d;
+            }
+        }
+        acc
+    }
+
+    #[cfg(feature = "std")]
+    fn euclid_dist(&self, other: &Self) -> T
+    where
+        T: Float,
+    {
+        (0..Self::DIMS)
+            .map(|i| self.at(i) - other.at(i))
+            .fold(T::zero(), |acc, d| acc + d * d)
+            .sqrt()
+    }
+
+    /// The Lp norm `(Σ|xᵢ|^p)^(1/p)`; an infinite `p` gives the largest component.
+    #[cfg(feature = "std")]
+    fn lp_norm(&self, p: T) -> T
+    where
+        T: Float,
+    {
+        lp(p, (0..Self::DIMS).map(|i| self.at(i)))
+    }
+
+    /// The distance between two points under the Lp norm.
+    #[cfg(feature = "std")]
+    fn lp_dist(&self, other: &Self, p: T) -> T
+    where
+        T: Float,
+    {
+        lp(p, (0..Self::DIMS).map(|i| self.at(i) - other.at(i)))
+    }
+}
+
+#[cfg(feature = "std")]
+fn lp<T: Float>(p: T, xs: impl Iterator<Item = T>) -> T {
+    let xs = xs.map(T::abs);
+    if p.is_infinite() {
+        xs.fold(T::zero(), T::max)
+    } else {
+        xs.fold(T::zero(), |acc, x| acc + x.powf(p)).powf(p.recip())
+    }
+}
+
+pub(crate) fn abs_diff<T: Scalar>(a: T, b: T) -> T {
+    if a > b {
+        a - b
+    } else {
+        b - a
+    }
 }
 
-impl<const D: usize> Vector<i64> for IntVector<D> {
+impl<const D: usize, T: Scalar> Vector<T> for IntVector<D, T> {
     const DIMS: usize = D;
 
-    fn at(&self, d: usize) -> i64 {
+    fn at(&self, d: usize) -> T {
         self.xs[d]
     }
 }
 
 #[derive(Clone, Eq, PartialEq, Debug, Hash)]
-pub struct Matrix<const D: usize> {
-    pub xs: [[i64; D]; D],
+pub struct Matrix<const D: usize, T = i64> {
+    pub xs: [[T; D]; D],
+}
+
+impl<const D: usize, T: Scalar> Matrix<D, T> {
+    pub fn identity() -> Self {
+        let mut xs = [[T::zero(); D]; D];
+        for (i, row) in xs.iter_mut().enumerate() {
+            row[i] = T::one();
+        }
+        Matrix { xs }
+    }
+
+    pub fn transpose(&self) -> Self {
+        let mut xs = self.xs;
+        for (i, row) in xs.iter_mut().enumerate() {
+            for (j, x) in row.iter_mut().enumerate() {
+                *x = self.xs[j][i];
+            }
+        }
+        Matrix { xs }
+    }
+
+    /// Converts each entry with `f`, e.g. to change the scalar type.
+    pub fn map<U: Scalar>(&self, f: impl Fn(T) -> U) -> Matrix<D, U> {
+        Matrix {
+            xs: self.xs.map(|row| row.map(&f)),
+        }
+    }
+
+    /// Raises the matrix to the `n`th power by repeated squaring.
+    pub fn pow(&self, mut n: u32) -> Self {
+        let mut ret = Self::identity();
+        let mut base = self.clone();
+        while n > 0 {
+            if n & 1 == 1 {
+                ret = &ret * &base;
+            }
+            n >>= 1;
+            if n > 0 {
+                base = &base * &base;
+            }
+        }
+        ret
+    }
+}
+
+impl<const D: usize, T: Scalar + Signed> Matrix<D, T> {
+    /// Fraction-free (Bareiss) Gauss-Jordan elimination of `[self | I]`.
+    ///
+    /// Every division along the way is exact, so integer matrices stay integral. On success the
+    /// left half has become `p·I` and the right half `p·self⁻¹`, where `p = ±det(self)`. Returns
+    /// that right half, `p`, and the sign relating `p` to the determinant; `None` if singular.
+    fn eliminate(&self) -> Option<(Self, T, T)> {
+        let mut left = self.xs;
+        let mut right = Self::identity().xs;
+        let mut sign = T::one();
+        let mut prev = T::one();
+
+        for k in 0..D {
+            let pivot_row = (k..D).find(|&r| !left[r][k].is_zero())?;
+            if pivot_row != k {
+                left.swap(k, pivot_row);
+                right.swap(k, pivot_row);
+                sign = -sign;
+            }
+
+            let pivot = left[k][k];
+            for i in (0..D).filter(|&i| i != k) {
+                let factor = left[i][k];
+                for j in 0..D {
+                    left[i][j] = (pivot * left[i][j] - factor * left[k][j]) / prev;
+                    right[i][j] = (pivot * right[i][j] - factor * right[k][j]) / prev;
+                }
+            }
+            prev = pivot;
+        }
+
+        Some((Matrix { xs: right }, prev, sign))
+    }
+
+    pub fn determinant(&self) -> T {
+        self.eliminate()
+            .map(|(_, p, sign)| p * sign)
+            .unwrap_or_else(T::zero)
+    }
+
+    /// The inverse, if it exists and is representable in `T`.
+    ///
+    /// For integer scalars this succeeds exactly for the unimodular matrices (determinant ±1);
+    /// use `rational_inverse` for the others.
+    pub fn inverse(&self) -> Option<Self> {
+        let (scaled, p, _) = self.eliminate()?;
+        let mut xs = scaled.xs;
+        for x in xs.iter_mut().flat_map(|row| row.iter_mut()) {
+            let q = *x / p;
+            if q * p != *x {
+                return None;
+            }
+            *x = q;
+        }
+        Some(Matrix { xs })
+    }
+}
+
+impl<const D: usize, T: Scalar + Signed + Integer> Matrix<D, T> {
+    /// The exact inverse over the rationals, for any non-singular integer matrix.
+    pub fn rational_inverse(&self) -> Option<Matrix<D, Ratio<T>>> {
+        self.map(Ratio::from_integer).inverse()
+    }
+}
+
+impl<const D: usize, T: Display> Display for Matrix<D, T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.write_str("[")?;
+        for (i, row) in self.xs.iter().enumerate() {
+            if i > 0 {
+                f.write_str(",")?;
+            }
+            write_list(f, row)?;
+        }
+        f.write_str("]")
+    }
+}
+
+impl<const D: usize, T: Scalar + Signed> Matrix<D, T> {
+    /// A quarter-turn rotation in the plane spanned by axes `from` and `to`.
+    ///
+    /// One quarter turn carries the `from` axis onto the `to` axis; negative turns go the other way.
+    pub fn plane_rotation(from: usize, to: usize, quarter_turns: i32) -> Self {
+        assert_ne!(from, to, "a rotation plane needs two distinct axes");
+        let (cos, sin) = match quarter_turns.rem_euclid(4) {
+            0 => (T::one(), T::zero()),
+            1 => (T::zero(), T::one()),
+            2 => (-T::one(), T::zero()),
+            _ => (T::zero(), -T::one()),
+        };
+
+        let mut xs = [[T::zero(); D]; D];
+        for (i, row) in xs.iter_mut().enumerate() {
+            row[i] = T::one();
+        }
+        xs[from][from] = cos;
+        xs[to][to] = cos;
+        xs[to][from] = sin;
+        xs[from][to] = -sin;
+
+        Matrix { xs }
+    }
+
+    /// Every axis-aligned orientation: the signed permutation matrices.
+    ///
+    /// With `mirror` false only the proper rotations (determinant +1) are produced, D!·2^(D-1) of
+    /// them; with `mirror` true the reflections (determinant -1) are included as well. See
+    /// `orientation_table` for the same list as a `const`.
+    #[cfg(feature = "alloc")]
+    pub fn orientations(mirror: bool) -> Vec<Self> {
+        let mut ret = Vec::new();
+        let mut perm = const_ops::first_permutation::<D>();
+        loop {
+            let inversions = const_ops::inversions(&perm);
+            for signs in 0..(1u32 << D) {
+                let flips = signs.count_ones() as usize;
+                if !mirror && (inversions + flips) % 2 == 1 {
+                    continue;
+                }
+
+                let mut xs = [[T::zero(); D]; D];
+                for (col, &row) in perm.iter().enumerate() {
+                    xs[row][col] = if signs & (1 << col) == 0 {
+                        T::one()
+                    } else {
+                        -T::one()
+                    };
+                }
+                ret.push(Matrix { xs });
+            }
+            if !const_ops::next_permutation(&mut perm) {
+                break;
+            }
+        }
+        ret
+    }
+}
+
+impl<T: Scalar + Signed> Matrix<3, T> {
+    /// A right-handed rotation by `quarter_turns` × 90° about `axis` (0 = x, 1 = y, 2 = z).
+    pub fn rotation(axis: usize, quarter_turns: i32) -> Self {
+        Matrix::plane_rotation((axis + 1) % 3, (axis + 2) % 3, quarter_turns)
+    }
 }
 
-impl<const D: usize> From<&[&[i64]]> for Matrix<D> {
-    fn from(src: &[&[i64]]) -> Self {
-        let mut xss: [[i64; D]; D] = [[0; D]; D];
+impl<const D: usize, T: Scalar> From<&[&[T]]> for Matrix<D, T> {
+    fn from(src: &[&[T]]) -> Self {
+        let mut xss: [[T; D]; D] = [[T::zero(); D]; D];
         for i in 0..D {
             xss[i].copy_from_slice(src[i])
         }
@@ -117,15 +698,15 @@ impl<const D: usize> From<&[&[i64]]> for Matrix<D> {
     }
 }
 
-impl<const D: usize> Mul<&IntVector<D>> for &Matrix<D> {
-    type Output = IntVector<D>;
+impl<const D: usize, T: Scalar> Mul<&IntVector<D, T>> for &Matrix<D, T> {
+    type Output = IntVector<D, T>;
 
-    fn mul(self, rhs: &IntVector<D>) -> Self::Output {
-        let mut ret = IntVector { xs: [0; D] };
+    fn mul(self, rhs: &IntVector<D, T>) -> Self::Output {
+        let mut ret = IntVector { xs: [T::zero(); D] };
 
         for i in 0..D {
             for j in 0..D {
-                ret.xs[i] += self.xs[i][j] * rhs.xs[j];
+                ret.xs[i] = ret.xs[i] + self.xs[i][j] * rhs.xs[j];
             }
         }
 
@@ -133,15 +714,17 @@ impl<const D: usize> Mul<&IntVector<D>> for &Matrix<D> {
     }
 }
 
-impl<const D: usize> Mul<Matrix<D>> for Matrix<D> {
-    type Output = Matrix<D>;
+impl<const D: usize, T: Scalar> Mul for &Matrix<D, T> {
+    type Output = Matrix<D, T>;
 
-    fn mul(self, rhs: Matrix<D>) -> Self::Output {
-        let mut ret = Matrix { xs: [[0; D]; D] };
+    fn mul(self, rhs: &Matrix<D, T>) -> Self::Output {
+        let mut ret = Matrix {
+            xs: [[T::zero(); D]; D],
+        };
         for i in 0..D {
             for j in 0..D {
                 for k in 0..D {
-                    ret.xs[i][j] += self.xs[i][k] * rhs.xs[k][j];
+                    ret.xs[i][j] = ret.xs[i][j] + self.xs[i][k] * rhs.xs[k][j];
                 }
             }
         }
@@ -150,9 +733,21 @@ impl<const D: usize> Mul<Matrix<D>> for Matrix<D> {
     }
 }
 
+impl<const D: usize, T: Scalar> Mul<Matrix<D, T>> for Matrix<D, T> {
+    type Output = Matrix<D, T>;
+
+    fn mul(self, rhs: Matrix<D, T>) -> Self::Output {
+        &self * &rhs
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use crate::{IntVector, Matrix, Vector};
+    use crate::{IntVector, Matrix, ParseVectorError, Vector};
+    use num::Rational64;
+    use proptest::prelude::*;
+    use std::collections::HashSet;
+    use std::ops::RangeInclusive;
 
     #[test]
     fn it_works() {
@@ -171,4 +766,345 @@ mod tests {
         let result = &r * &x;
         assert_eq!(IntVector::from([0, 1, 0]), result);
     }
+
+    #[test]
+    fn unsigned_manh_dist() {
+        let a: IntVector<2, u8> = IntVector::from([2, 7]);
+        let b = IntVector::from([5, 1]);
+        assert_eq!(9, a.manh_dist(&b));
+    }
+
+    #[test]
+    fn float_vectors() {
+        let a: IntVector<2, f64> = IntVector::from([0.5, 1.5]);
+        let b = IntVector::from([0.25, 0.25]);
+        assert_eq!(IntVector::from([0.75, 1.75]), a + b);
+        assert_eq!(2.5, a.magnitude2());
+    }
+
+    #[test]
+    fn rational_rotation() {
+        let half = Rational64::new(1, 2);
+        let x = IntVector::from([half, Rational64::from(0)]);
+        let r: Matrix<2, Rational64> = Matrix {
+            xs: [[0.into(), (-1).into()], [1.into(), 0.into()]],
+        };
+
+        assert_eq!(IntVector::from([0.into(), half]), &r * &x);
+        assert_eq!("[0,1/2]", (&r * &x).to_string());
+    }
+
+    #[test]
+    fn map_scalar() {
+        let a: IntVector<3, i32> = IntVector::from([1, -2, 3]);
+        let wide: IntVector<3, i128> = a.map(i128::from);
+        assert_eq!(IntVector::from([1i128, -2, 3]), wide);
+    }
+
+    #[test]
+    #[allow(clippy::op_ref)]
+    fn owned_and_borrowed_ops() {
+        let a = IntVector::from([1, 2, 3]);
+        let b = IntVector::from([4, 5, 6]);
+        let sum = IntVector::from([5, 7, 9]);
+        assert_eq!(sum, a + b);
+        assert_eq!(sum, &a + b);
+        assert_eq!(sum, a + &b);
+        assert_eq!(sum, &a + &b);
+        assert_eq!(IntVector::from([-3, -3, -3]), a - b);
+        assert_eq!(IntVector::from([-1, -2, -3]), -a);
+        assert_eq!(IntVector::from([2, 4, 6]), a * 2);
+        assert_eq!(IntVector::from([2, 2, 3]), b / 2);
+    }
+
+    #[test]
+    fn assign_ops() {
+        let mut a = IntVector::from([1, 2]);
+        a += IntVector::from([10, 20]);
+        assert_eq!(IntVector::from([11, 22]), a);
+        a -= &IntVector::from([1, 2]);
+        assert_eq!(IntVector::from([10, 20]), a);
+        a[1] = 7;
+        assert_eq!(7, a[1]);
+    }
+
+    #[test]
+    fn component_wise() {
+        let a = IntVector::from([-3, 0, 5]);
+        let b = IntVector::from([2, -1, 5]);
+        assert_eq!(IntVector::from([-3, -1, 5]), a.min(&b));
+        assert_eq!(IntVector::from([2, 0, 5]), a.max(&b));
+        assert_eq!(IntVector::from([3, 0, 5]), a.abs());
+        assert_eq!(IntVector::from([-1, 0, 1]), a.signum());
+    }
+
+    #[test]
+    fn products() {
+        let x: IntVector<3> = IntVector::unit(0);
+        let y = IntVector::unit(1);
+        assert_eq!(0, x.dot(&y));
+        assert_eq!(IntVector::unit(2), x.cross(&y));
+        assert_eq!(
+            32,
+            IntVector::from([1, 2, 3]).dot(&IntVector::from([4, 5, 6]))
+        );
+    }
+
+    #[test]
+    fn iteration() {
+        let a = IntVector::from([1, 2, 3]);
+        assert_eq!(6, a.iter().sum::<i32>());
+        assert_eq!(vec![1, 2, 3], a.into_iter().collect::<Vec<_>>());
+        assert_eq!(IntVector::<3>::zero(), IntVector::from([0, 0, 0]));
+    }
+
+    #[test]
+    fn rotation_about_axes() {
+        let x = IntVector::from([1, 0, 0]);
+        let y = IntVector::from([0, 1, 0]);
+        let z = IntVector::from([0, 0, 1]);
+        assert_eq!(z, &Matrix::rotation(0, 1) * &y);
+        assert_eq!(x, &Matrix::rotation(1, 1) * &z);
+        assert_eq!(y, &Matrix::rotation(2, 1) * &x);
+        assert_eq!(-y, &Matrix::rotation(2, -1) * &x);
+        assert_eq!(Matrix::<3>::rotation(0, 4), Matrix::rotation(0, 0));
+    }
+
+    fn assert_closed<const D: usize>(group: &[Matrix<D>]) {
+        let members: HashSet<&Matrix<D>> = group.iter().collect();
+        assert_eq!(group.len(), members.len());
+        for a in group {
+            for b in group {
+                assert!(members.contains(&(a * b)));
+            }
+        }
+    }
+
+    #[test]
+    fn orientation_groups() {
+        let rotations: Vec<Matrix<3>> = Matrix::orientations(false);
+        assert_eq!(24, rotations.len());
+        assert_closed(&rotations);
+        for axis in 0..3 {
+            for turns in 0..4 {
+                assert!(rotations.contains(&Matrix::rotation(axis, turns)));
+            }
+        }
+
+        let with_mirrors: Vec<Matrix<3>> = Matrix::orientations(true);
+        assert_eq!(48, with_mirrors.len());
+        assert_closed(&with_mirrors);
+
+        let flat: Vec<Matrix<2>> = Matrix::orientations(false);
+        assert_eq!(4, flat.len());
+        assert_closed(&flat);
+
+        let hyper: Vec<Matrix<4>> = Matrix::orientations(false);
+        assert_eq!(192, hyper.len());
+        assert_closed(&hyper);
+    }
+
+    #[test]
+    fn identity_and_transpose() {
+        let m = Matrix {
+            xs: [[1, 2, 3], [4, 5, 6], [7, 8, 9]],
+        };
+        assert_eq!(m, &m * &Matrix::identity());
+        assert_eq!(
+            Matrix {
+                xs: [[1, 4, 7], [2, 5, 8], [3, 6, 9]],
+            },
+            m.transpose()
+        );
+        assert_eq!(m, m.transpose().transpose());
+    }
+
+    #[test]
+    fn determinants() {
+        let singular = Matrix {
+            xs: [[1, 2, 3], [4, 5, 6], [7, 8, 9]],
+        };
+        assert_eq!(0, singular.determinant());
+        assert_eq!(None, singular.inverse());
+        assert_eq!(None, singular.rational_inverse());
+
+        let m = Matrix {
+            xs: [[0, 2, 1], [3, -1, 2], [1, 1, 4]],
+        };
+        assert_eq!(-16, m.determinant());
+        assert_eq!(1, Matrix::<4>::identity().determinant());
+        for r in Matrix::<3>::orientations(true) {
+            assert_eq!(1, r.determinant().abs());
+        }
+    }
+
+    #[test]
+    fn inverses() {
+        for r in Matrix::<3>::orientations(false) {
+            let inv = r.inverse().unwrap();
+            assert_eq!(r.transpose(), inv);
+            assert_eq!(Matrix::identity(), &r * &inv);
+        }
+
+        let shear = Matrix {
+            xs: [[1, 3, 0], [0, 1, 0], [2, 6, 1]],
+        };
+        assert_eq!(Matrix::identity(), &shear * &shear.inverse().unwrap());
+
+        let m = Matrix {
+            xs: [[2, 1], [1, 1]],
+        };
+        assert_eq!(
+            Some(Matrix {
+                xs: [[1, -1], [-1, 2]]
+            }),
+            m.inverse()
+        );
+
+        let m = Matrix {
+            xs: [[0, 2, 1], [3, -1, 2], [1, 1, 4]],
+        };
+        assert_eq!(None, m.inverse());
+        let inv = m.rational_inverse().unwrap();
+        assert_eq!(Matrix::identity(), &m.map(Rational64::from_integer) * &inv);
+        assert_eq!(Rational64::new(3, 8), inv.xs[0][0]);
+    }
+
+    #[test]
+    fn powers() {
+        let fib = Matrix {
+            xs: [[1u64, 1], [1, 0]],
+        };
+        assert_eq!(Matrix::identity(), fib.pow(0));
+        assert_eq!(fib, fib.pow(1));
+        assert_eq!(12586269025, fib.pow(50).xs[0][1]);
+        assert_eq!(Matrix::<3>::identity(), Matrix::rotation(1, 1).pow(4));
+    }
+
+    #[test]
+    fn display_matrix() {
+        let m = Matrix {
+            xs: [[1, 0], [0, -1]],
+        };
+        assert_eq!("[[1,0],[0,-1]]", m.to_string());
+    }
+
+    #[test]
+    fn parse_vectors() {
+        let v = IntVector::from([1, -2, 3]);
+        assert_eq!(Ok(v), v.to_string().parse());
+        assert_eq!(Ok(v), "1,-2,3".parse());
+        assert_eq!(Ok(v), " [ 1, -2, 3 ] ".parse());
+        assert_eq!(
+            Err(ParseVectorError::WrongLength {
+                expected: 3,
+                found: 2
+            }),
+            "1,2".parse::<IntVector<3>>()
+        );
+        assert_eq!(
+            Err(ParseVectorError::BadComponent {
+                index: 1,
+                token: "x".to_string()
+            }),
+            "1,x,3".parse::<IntVector<3>>()
+        );
+    }
+
+    #[test]
+    fn distances() {
+        let a = IntVector::from([1, -4, 2]);
+        let b = IntVector::from([-2, 1, 2]);
+        assert_eq!(8, a.manh_dist(&b));
+        assert_eq!(5, a.cheb_dist(&b));
+
+        let a: IntVector<2, f64> = IntVector::from([0.0, 0.0]);
+        let b = IntVector::from([3.0, -4.0]);
+        assert_eq!(5.0, a.euclid_dist(&b));
+        assert_eq!(5.0, b.lp_norm(2.0));
+        assert_eq!(7.0, a.lp_dist(&b, 1.0));
+        assert_eq!(4.0, a.lp_dist(&b, f64::INFINITY));
+        assert!((b.lp_norm(3.0) - 91f64.cbrt()).abs() < 1e-12);
+    }
+
+    #[test]
+    fn neighbourhoods() {
+        assert_eq!(8, IntVector::<2>::moore_offsets(1).len());
+        assert_eq!(4, IntVector::<2>::von_neumann_offsets(1).len());
+        assert_eq!(24, IntVector::<2>::moore_offsets(2).len());
+        assert_eq!(12, IntVector::<2>::von_neumann_offsets(2).len());
+        assert_eq!(26, IntVector::<3>::moore_offsets(1).len());
+        assert_eq!(6, IntVector::<3>::von_neumann_offsets(1).len());
+        assert_eq!(80, IntVector::<4>::moore_offsets(1).len());
+
+        let center = IntVector::from([5, 5]);
+        let around: HashSet<_> = center.von_neumann(1).collect();
+        let expected: HashSet<_> = [[4, 5], [6, 5], [5, 4], [5, 6]]
+            .into_iter()
+            .map(IntVector::from)
+            .collect();
+        assert_eq!(expected, around);
+        assert!(center.moore(3).all(|p| p.cheb_dist(&center) <= 3));
+    }
+
+    /// 3×3 matrices with entries in `entries`, kept small enough that products a few deep stay
+    /// well inside an `i64`.
+    fn matrix(entries: RangeInclusive<i64>) -> impl Strategy<Value = Matrix<3>> {
+        prop::array::uniform3(prop::array::uniform3(entries)).prop_map(|xs| Matrix { xs })
+    }
+
+    fn vector() -> impl Strategy<Value = IntVector<3>> {
+        prop::array::uniform3(-100i64..=100).prop_map(IntVector::from)
+    }
+
+    proptest! {
+        #[test]
+        fn matrix_products_associate(
+            a in matrix(-100..=100),
+            b in matrix(-100..=100),
+            c in matrix(-100..=100),
+        ) {
+            prop_assert_eq!(&(&a * &b) * &c, &a * &(&b * &c));
+        }
+
+        #[test]
+        fn identity_is_neutral(a in matrix(-100..=100)) {
+            prop_assert_eq!(&Matrix::identity() * &a, a.clone());
+            prop_assert_eq!(&a * &Matrix::identity(), a);
+        }
+
+        #[test]
+        fn products_compose_transforms(
+            a in matrix(-100..=100),
+            b in matrix(-100..=100),
+            v in vector(),
+        ) {
+            prop_assert_eq!(&(&a * &b) * &v, &a * &(&b * &v));
+        }
+
+        #[test]
+        fn transposes_reverse_products(a in matrix(-100..=100), b in matrix(-100..=100)) {
+            prop_assert_eq!((&a * &b).transpose(), &b.transpose() * &a.transpose());
+        }
+
+        #[test]
+        fn determinants_multiply(a in matrix(-9..=9), b in matrix(-9..=9)) {
+            prop_assert_eq!(
+                (&a * &b).determinant(),
+                a.determinant() * b.determinant()
+            );
+        }
+
+        #[test]
+        fn powers_add(a in matrix(-3..=3), m in 0u32..5, n in 0u32..5) {
+            prop_assert_eq!(a.pow(m + n), &a.pow(m) * &a.pow(n));
+        }
+
+        #[test]
+        fn rational_inverses_undo(a in matrix(-5..=5)) {
+            prop_assume!(a.determinant() != 0);
+            let inverse = a.rational_inverse().unwrap();
+            prop_assert_eq!(&a.map(Rational64::from_integer) * &inverse, Matrix::identity());
+        }
+    }
 }
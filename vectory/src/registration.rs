@@ -0,0 +1,231 @@
+//! Rigid registration of point clouds that differ by an unknown orientation and offset.
+//!
+//! Candidate correspondences are pruned with pairwise-distance fingerprints: squared distances
+//! between points of one cloud survive any rotation and translation, so two points can only
+//! correspond if their clouds agree on enough distances from them.
+
+use crate::{Affine, IntVector, Matrix, Scalar, Vector};
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::hash::Hash;
+
+/// A transform taking points of the moving cloud into the fixed cloud's frame.
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub struct Fit<const D: usize, T = i64> {
+    pub transform: Affine<D, T>,
+    /// How many transformed points land on a point of the fixed cloud.
+    pub overlap: usize,
+}
+
+/// The result of placing many clouds in the frame of the first.
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub struct Alignment<const D: usize, T = i64> {
+    /// The pose of each cloud relative to cloud 0, or `None` if it couldn't be placed.
+    pub poses: Vec<Option<Affine<D, T>>>,
+    /// The `(parent, child)` fits that placed each cloud: a spanning tree rooted at cloud 0.
+    pub tree: Vec<(usize, usize)>,
+    /// Indices of the clouds with no chain of fits back to cloud 0.
+    pub unplaced: Vec<usize>,
+}
+
+/// Each point of a cloud with its fingerprint.
+type Fingerprints<const D: usize, T> = HashMap<IntVector<D, T>, Vec<T>>;
+
+/// Sorted squared distances from each point to every other point in its cloud.
+fn fingerprints<const D: usize, T: Scalar + Ord + Hash>(
+    cloud: &HashSet<IntVector<D, T>>,
+) -> Fingerprints<D, T> {
+    cloud
+        .iter()
+        .map(|p| {
+            let mut ds: Vec<T> = cloud
+                .iter()
+                .filter(|q| *q != p)
+                .map(|q| (q - p).magnitude2())
+                .collect();
+            ds.sort_unstable();
+            (*p, ds)
+        })
+        .collect()
+}
+
+/// Size of the multiset intersection of two sorted lists.
+fn shared<T: Ord>(a: &[T], b: &[T]) -> usize {
+    let (mut i, mut j, mut n) = (0, 0, 0);
+    while i < a.len() && j < b.len() {
+        match a[i].cmp(&b[j]) {
+            std::cmp::Ordering::Less => i += 1,
+            std::cmp::Ordering::Greater => j += 1,
+            std::cmp::Ordering::Equal => {
+                n += 1;
+                i += 1;
+                j += 1;
+            }
+        }
+    }
+    n
+}
+
+/// Finds the transform from `rotations` × translations that maps the most points of `moving`
+/// onto `fixed`, provided at least `min_overlap` of them line up.
+pub fn register<const D: usize, T: Scalar + Ord + Hash>(
+    fixed: &HashSet<IntVector<D, T>>,
+    moving: &HashSet<IntVector<D, T>>,
+    rotations: &[Matrix<D, T>],
+    min_overlap: usize,
+) -> Option<Fit<D, T>> {
+    let fixed_prints = fingerprints(fixed);
+    let moving_prints = fingerprints(moving);
+    register_printed(
+        (fixed, &fixed_prints),
+        (moving, &moving_prints),
+        rotations,
+        min_overlap,
+    )
+}
+
+/// [`register`], for clouds whose fingerprints have already been taken.
+fn register_printed<const D: usize, T: Scalar + Ord + Hash>(
+    (fixed, fixed_prints): (&HashSet<IntVector<D, T>>, &Fingerprints<D, T>),
+    (moving, moving_prints): (&HashSet<IntVector<D, T>>, &Fingerprints<D, T>),
+    rotations: &[Matrix<D, T>],
+    min_overlap: usize,
+) -> Option<Fit<D, T>> {
+    // A true correspondence shares the distances to the other min_overlap - 1 matched points
+    let needed = min_overlap.saturating_sub(1);
+    let candidates: Vec<(&IntVector<D, T>, &IntVector<D, T>)> = fixed_prints
+        .iter()
+        .flat_map(|(a, fa)| {
+            moving_prints
+                .iter()
+                .filter(move |(_, fb)| shared(fa, fb) >= needed)
+                .map(move |(b, _)| (a, b))
+        })
+        .collect();
+
+    let mut best: Option<Fit<D, T>> = None;
+    for rotation in rotations {
+        // Every candidate pair votes for the offset that would make it line up
+        let mut votes: HashMap<IntVector<D, T>, usize> = HashMap::new();
+        for &(a, b) in candidates.iter() {
+            *votes.entry(*a - rotation * b).or_insert(0) += 1;
+        }
+
+        let top = votes.into_iter().max_by_key(|(_, n)| *n);
+        if let Some((offset, n)) = top {
+            if n < min_overlap || best.as_ref().is_some_and(|b| n <= b.overlap) {
+                continue;
+            }
+            let transform = Affine::new(rotation.clone(), offset);
+            let overlap = moving
+                .iter()
+                .filter(|p| fixed.contains(&transform.apply(p)))
+                .count();
+            if overlap >= min_overlap && best.as_ref().is_none_or(|b| overlap > b.overlap) {
+                best = Some(Fit { transform, overlap });
+            }
+        }
+    }
+    best
+}
+
+/// Places every cloud in the frame of `clouds[0]`, growing a spanning tree of pairwise fits
+/// outward from it breadth first.
+pub fn realign<const D: usize, T: Scalar + Ord + Hash>(
+    clouds: &[HashSet<IntVector<D, T>>],
+    rotations: &[Matrix<D, T>],
+    min_overlap: usize,
+) -> Alignment<D, T> {
+    // Each cloud is tried against several others, so its fingerprints are taken once up front
+    let prints: Vec<_> = clouds.iter().map(fingerprints).collect();
+    let mut poses: Vec<Option<Affine<D, T>>> = vec![None; clouds.len()];
+    let mut tree = Vec::new();
+    let mut queue = VecDeque::new();
+
+    if !clouds.is_empty() {
+        poses[0] = Some(Affine::identity());
+        queue.push_back(0);
+    }
+
+    while let Some(parent) = queue.pop_front() {
+        let parent_pose = poses[parent].clone().unwrap();
+        for child in 0..clouds.len() {
+            if poses[child].is_some() {
+                continue;
+            }
+            let fit = register_printed(
+                (&clouds[parent], &prints[parent]),
+                (&clouds[child], &prints[child]),
+                rotations,
+                min_overlap,
+            );
+            if let Some(fit) = fit {
+                poses[child] = Some(&parent_pose * &fit.transform);
+                tree.push((parent, child));
+                queue.push_back(child);
+            }
+        }
+    }
+
+    let unplaced = (0..clouds.len()).filter(|&i| poses[i].is_none()).collect();
+    Alignment {
+        poses,
+        tree,
+        unplaced,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::registration::{realign, register};
+    use crate::{Affine, IntVector, Matrix};
+    use std::collections::HashSet;
+
+    fn cloud(points: &[[i64; 3]]) -> HashSet<IntVector<3>> {
+        points.iter().copied().map(IntVector::from).collect()
+    }
+
+    fn base() -> HashSet<IntVector<3>> {
+        cloud(&[
+            [0, 0, 0],
+            [5, 1, 2],
+            [-3, 7, 1],
+            [9, -4, 6],
+            [2, 2, -8],
+            [11, 3, 3],
+            [-6, -2, 4],
+        ])
+    }
+
+    #[test]
+    fn recovers_known_transform() {
+        let rotations: Vec<Matrix<3>> = Matrix::orientations(false);
+        let pose = Affine::new(rotations[7].clone(), IntVector::from([100, -20, 3]));
+        let fixed = base();
+        // The moving cloud sees the same points from the other side of `pose`
+        let inverse = pose.inverse().unwrap();
+        let mut moving: HashSet<_> = inverse.apply_all(&fixed);
+        moving.insert(IntVector::from([1000, 1000, 1000]));
+
+        let fit = register(&fixed, &moving, &rotations, 6).unwrap();
+        assert_eq!(pose, fit.transform);
+        assert_eq!(7, fit.overlap);
+
+        assert_eq!(None, register(&fixed, &moving, &rotations, 8));
+    }
+
+    #[test]
+    fn realign_places_clouds_and_reports_strays() {
+        let rotations: Vec<Matrix<3>> = Matrix::orientations(false);
+        let a = base();
+        let shift = Affine::new(rotations[3].clone(), IntVector::from([4, 4, 4]));
+        let b: HashSet<_> = shift.inverse().unwrap().apply_all(&a);
+        let c: HashSet<_> = shift.inverse().unwrap().apply_all(&b);
+        let stray = cloud(&[[1, 1, 1], [2, 3, 5], [8, 13, 21]]);
+
+        let alignment = realign(&[a, c, stray, b], &rotations, 7);
+        assert_eq!(vec![2], alignment.unplaced);
+        assert_eq!(Some(shift.clone()), alignment.poses[3]);
+        assert_eq!(Some(&shift * &shift), alignment.poses[1]);
+        assert_eq!(2, alignment.tree.len());
+    }
+}
@@ -0,0 +1,299 @@
+use crate::{IntVector, Scalar};
+#[cfg(feature = "alloc")]
+use alloc::{vec, vec::Vec};
+use num::rational::Ratio;
+use num::{Integer, Signed};
+
+/// A closed line segment between two lattice points.
+#[derive(Copy, Clone, Eq, PartialEq, Debug, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Segment<const D: usize, T = i64> {
+    pub start: IntVector<D, T>,
+    pub end: IntVector<D, T>,
+}
+
+/// Where two segments meet.
+#[derive(Clone, Debug)]
+pub enum Intersection<T> {
+    /// A single point, which need not be a lattice point.
+    Point(IntVector<2, Ratio<T>>),
+    /// Collinear segments sharing more than one point, oriented like the first segment.
+    Overlap(Segment<2, T>),
+}
+
+// Ratio only compares for integer T, which derive can't express
+impl<T: Clone + Integer> PartialEq for Intersection<T> {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Intersection::Point(a), Intersection::Point(b)) => a == b,
+            (Intersection::Overlap(a), Intersection::Overlap(b)) => a == b,
+            _ => false,
+        }
+    }
+}
+
+impl<T: Clone + Integer> Eq for Intersection<T> {}
+
+impl<const D: usize, T: Scalar + Signed + Integer> Segment<D, T> {
+    pub const fn new(start: IntVector<D, T>, end: IntVector<D, T>) -> Self {
+        Segment { start, end }
+    }
+
+    pub fn direction(&self) -> IntVector<D, T> {
+        self.end - self.start
+    }
+
+    /// The smallest lattice step along the segment: the direction divided by the gcd of its
+    /// components. Zero for a degenerate segment.
+    pub fn step(&self) -> IntVector<D, T> {
+        let dir = self.direction();
+        let g = dir.iter().fold(T::zero(), |acc, x| acc.gcd(x));
+        if g.is_zero() {
+            dir
+        } else {
+            dir / g
+        }
+    }
+
+    /// Every lattice point on the segment, from `start` to `end`, whatever the slope.
+    pub fn lattice_points(&self) -> impl Iterator<Item = IntVector<D, T>> {
+        let step = self.step();
+        let mut next = Some(self.start);
+        let end = self.end;
+        core::iter::from_fn(move || {
+            let ret = next?;
+            next = if ret == end { None } else { Some(ret + step) };
+            Some(ret)
+        })
+    }
+}
+
+fn cross<T: Scalar>(a: &IntVector<2, T>, b: &IntVector<2, T>) -> T {
+    a[0] * b[1] - a[1] * b[0]
+}
+
+impl<T: Scalar + Signed + Integer> Segment<2, T> {
+    /// Rasterizes the segment with Bresenham's algorithm: one point per step along the major
+    /// axis, so there are no gaps even when the slope doesn't pass through lattice points.
+    #[cfg(feature = "alloc")]
+    pub fn bresenham(&self) -> Vec<IntVector<2, T>> {
+        let d = self.direction().abs();
+        let s = self.direction().signum();
+        let (dx, dy) = (d[0], -d[1]);
+
+        let mut ret = Vec::new();
+        let mut p = self.start;
+        let mut err = dx + dy;
+        loop {
+            ret.push(p);
+            if p == self.end {
+                break;
+            }
+            let e2 = err + err;
+            if e2 >= dy {
+                err = err + dy;
+                p[0] = p[0] + s[0];
+            }
+            if e2 <= dx {
+                err = err + dx;
+                p[1] = p[1] + s[1];
+            }
+        }
+        ret
+    }
+
+    pub fn contains(&self, p: &IntVector<2, T>) -> bool {
+        let r = self.direction();
+        let rel = p - self.start;
+        if !cross(&rel, &r).is_zero() {
+            return false;
+        }
+        let t = rel.dot(&r);
+        T::zero() <= t && t <= r.dot(&r)
+    }
+
+    /// The exact intersection of two segments, if they meet.
+    pub fn intersection(&self, other: &Self) -> Option<Intersection<T>> {
+        let p = self.start;
+        let r = self.direction();
+        let q = other.start;
+        let s = other.direction();
+        let point = |v: IntVector<2, T>| Intersection::Point(v.map(Ratio::from_integer));
+
+        if r == IntVector::zero() {
+            return other.contains(&p).then(|| point(p));
+        }
+        if s == IntVector::zero() {
+            return self.contains(&q).then(|| point(q));
+        }
+
+        let qp = q - p;
+        let denom = cross(&r, &s);
+        if denom.is_zero() {
+            if !cross(&qp, &r).is_zero() {
+                // Parallel, on different lines
+                return None;
+            }
+
+            // Collinear: compare positions along r, scaled by r·r so they stay integral
+            let rr = r.dot(&r);
+            let tq = qp.dot(&r);
+            let tq_end = (other.end - p).dot(&r);
+            let (lo, hi) = if tq < tq_end {
+                (tq, tq_end)
+            } else {
+                (tq_end, tq)
+            };
+            let lo = if lo > T::zero() { lo } else { T::zero() };
+            let hi = if hi < rr { hi } else { rr };
+            if lo > hi {
+                return None;
+            }
+
+            // Both ends of the overlap are ends of one segment or the other
+            let at = |t: T| {
+                if t.is_zero() {
+                    p
+                } else if t == rr {
+                    self.end
+                } else if t == tq {
+                    q
+                } else {
+                    other.end
+                }
+            };
+            return if lo == hi {
+                Some(point(at(lo)))
+            } else {
+                Some(Intersection::Overlap(Segment::new(at(lo), at(hi))))
+            };
+        }
+
+        // Solve p + t·r = q + u·s; both parameters must land in [0, 1]
+        let t = Ratio::new(cross(&qp, &s), denom);
+        let u = Ratio::new(cross(&qp, &r), denom);
+        let zero = Ratio::from_integer(T::zero());
+        let one = Ratio::from_integer(T::one());
+        let in_range = |x: Ratio<T>| zero <= x && x <= one;
+        if !in_range(t) || !in_range(u) {
+            return None;
+        }
+
+        let p = p.map(Ratio::from_integer);
+        let r = r.map(Ratio::from_integer);
+        Some(Intersection::Point(p + r * t))
+    }
+}
+
+impl<T: Scalar + Signed + Integer> Intersection<T> {
+    /// The lattice points shared by both segments.
+    #[cfg(feature = "alloc")]
+    pub fn lattice_points(&self) -> Vec<IntVector<2, T>> {
+        match self {
+            Intersection::Point(p) if p.iter().all(|x| x.is_integer()) => {
+                vec![p.map(|x| x.to_integer())]
+            }
+            Intersection::Point(_) => vec![],
+            Intersection::Overlap(s) => s.lattice_points().collect(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{IntVector, Intersection, Segment};
+    use num::Rational64;
+
+    fn seg(a: [i64; 2], b: [i64; 2]) -> Segment<2> {
+        Segment::new(IntVector::from(a), IntVector::from(b))
+    }
+
+    #[test]
+    fn lattice_points_any_slope() {
+        let points: Vec<_> = seg([0, 0], [6, 4]).lattice_points().collect();
+        assert_eq!(
+            vec![[0, 0], [3, 2], [6, 4]]
+                .into_iter()
+                .map(IntVector::from)
+                .collect::<Vec<_>>(),
+            points
+        );
+
+        assert_eq!(9, seg([8, 0], [0, 8]).lattice_points().count());
+        assert_eq!(4, seg([9, 0], [0, 6]).lattice_points().count());
+        assert_eq!(1, seg([2, 2], [2, 2]).lattice_points().count());
+
+        let diag = Segment::new(IntVector::from([0, 0, 0]), IntVector::from([-2, 2, 2]));
+        assert_eq!(3, diag.lattice_points().count());
+    }
+
+    #[test]
+    fn bresenham_has_no_gaps() {
+        for s in [
+            seg([0, 0], [7, 3]),
+            seg([5, -2], [-1, 9]),
+            seg([3, 3], [3, -3]),
+        ] {
+            let points = s.bresenham();
+            assert_eq!(s.start, points[0]);
+            assert_eq!(s.end, *points.last().unwrap());
+            let d = s.direction().abs();
+            assert_eq!(d[0].max(d[1]) as usize + 1, points.len());
+            for w in points.windows(2) {
+                let step = (w[1] - w[0]).abs();
+                assert!(step[0] <= 1 && step[1] <= 1);
+            }
+        }
+        assert_eq!(
+            seg([0, 0], [4, 4]).lattice_points().collect::<Vec<_>>(),
+            seg([0, 0], [4, 4]).bresenham()
+        );
+    }
+
+    #[test]
+    fn crossing_segments() {
+        let x = seg([0, 0], [4, 4]).intersection(&seg([0, 4], [4, 0]));
+        assert_eq!(
+            Some(Intersection::Point(IntVector::from([2.into(), 2.into()]))),
+            x
+        );
+        assert_eq!(vec![IntVector::from([2, 2])], x.unwrap().lattice_points());
+
+        let x = seg([0, 0], [1, 1])
+            .intersection(&seg([0, 1], [1, 0]))
+            .unwrap();
+        let half = Rational64::new(1, 2);
+        assert_eq!(Intersection::Point(IntVector::from([half, half])), x);
+        assert!(x.lattice_points().is_empty());
+
+        assert_eq!(None, seg([0, 0], [1, 1]).intersection(&seg([3, 0], [2, 1])));
+        assert_eq!(None, seg([0, 0], [4, 0]).intersection(&seg([0, 1], [4, 1])));
+    }
+
+    #[test]
+    fn collinear_segments() {
+        assert_eq!(
+            Some(Intersection::Overlap(seg([2, 0], [4, 0]))),
+            seg([0, 0], [4, 0]).intersection(&seg([6, 0], [2, 0]))
+        );
+        assert_eq!(
+            Some(Intersection::Overlap(seg([2, 2], [1, 1]))),
+            seg([3, 3], [0, 0]).intersection(&seg([1, 1], [2, 2]))
+        );
+        assert_eq!(
+            Some(Intersection::Point(IntVector::from([4.into(), 0.into()]))),
+            seg([0, 0], [4, 0]).intersection(&seg([4, 0], [9, 0]))
+        );
+        assert_eq!(None, seg([0, 0], [4, 0]).intersection(&seg([5, 0], [9, 0])));
+    }
+
+    #[test]
+    fn degenerate_segments() {
+        let dot = seg([2, 2], [2, 2]);
+        assert_eq!(
+            Some(Intersection::Point(IntVector::from([2.into(), 2.into()]))),
+            seg([0, 0], [4, 4]).intersection(&dot)
+        );
+        assert_eq!(None, seg([0, 0], [4, 0]).intersection(&dot));
+    }
+}
@@ -0,0 +1,99 @@
+//! Serde support: vectors as flat sequences, matrices as sequences of rows.
+
+use crate::{IntVector, Matrix};
+use alloc::vec::Vec;
+use core::fmt::Formatter;
+use core::marker::PhantomData;
+use serde::de::{Error, SeqAccess, Visitor};
+use serde::ser::SerializeSeq;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+impl<const D: usize, T: Serialize> Serialize for IntVector<D, T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut seq = serializer.serialize_seq(Some(D))?;
+        for x in self.xs.iter() {
+            seq.serialize_element(x)?;
+        }
+        seq.end()
+    }
+}
+
+impl<const D: usize, T: Serialize> Serialize for Matrix<D, T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut seq = serializer.serialize_seq(Some(D))?;
+        for row in self.xs.iter() {
+            seq.serialize_element(row.as_slice())?;
+        }
+        seq.end()
+    }
+}
+
+/// Reads a sequence of exactly `D` elements into an array.
+struct ArrayVisitor<const D: usize, T>(PhantomData<T>);
+
+impl<'de, const D: usize, T: Deserialize<'de>> Visitor<'de> for ArrayVisitor<D, T> {
+    type Value = [T; D];
+
+    fn expecting(&self, f: &mut Formatter) -> core::fmt::Result {
+        write!(f, "a sequence of {} elements", D)
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+        let mut xs = Vec::with_capacity(D);
+        while let Some(x) = seq.next_element()? {
+            if xs.len() == D {
+                return Err(A::Error::invalid_length(D + 1, &self));
+            }
+            xs.push(x);
+        }
+        xs.try_into()
+            .map_err(|xs: Vec<T>| A::Error::invalid_length(xs.len(), &self))
+    }
+}
+
+impl<'de, const D: usize, T: Deserialize<'de>> Deserialize<'de> for IntVector<D, T> {
+    fn deserialize<De: Deserializer<'de>>(deserializer: De) -> Result<Self, De::Error> {
+        let xs = deserializer.deserialize_seq(ArrayVisitor::<D, T>(PhantomData))?;
+        Ok(IntVector { xs })
+    }
+}
+
+impl<'de, const D: usize, T: Deserialize<'de>> Deserialize<'de> for Matrix<D, T> {
+    fn deserialize<De: Deserializer<'de>>(deserializer: De) -> Result<Self, De::Error> {
+        let rows = deserializer.deserialize_seq(ArrayVisitor::<D, IntVector<D, T>>(PhantomData))?;
+        Ok(Matrix {
+            xs: rows.map(|row| row.xs),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Affine, IntVector, Matrix};
+
+    #[test]
+    fn vector_as_array() {
+        let v = IntVector::from([1, -2, 3]);
+        let json = serde_json::to_string(&v).unwrap();
+        assert_eq!("[1,-2,3]", json);
+        assert_eq!(v, serde_json::from_str(&json).unwrap());
+
+        assert!(serde_json::from_str::<IntVector<3>>("[1,2]").is_err());
+        assert!(serde_json::from_str::<IntVector<3>>("[1,2,3,4]").is_err());
+    }
+
+    #[test]
+    fn matrix_as_nested_arrays() {
+        let m: Matrix<3> = Matrix::rotation(2, 1);
+        let json = serde_json::to_string(&m).unwrap();
+        assert_eq!("[[0,-1,0],[1,0,0],[0,0,1]]", json);
+        assert_eq!(m, serde_json::from_str(&json).unwrap());
+    }
+
+    #[test]
+    fn pose_round_trip() {
+        let pose = Affine::new(Matrix::rotation(0, 3), IntVector::from([68, -1246, -43]));
+        let json = serde_json::to_string(&pose).unwrap();
+        assert_eq!(pose, serde_json::from_str(&json).unwrap());
+    }
+}